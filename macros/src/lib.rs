@@ -0,0 +1,127 @@
+//! Procedural macros for [securiety](https://docs.rs/securiety).
+//!
+//! Use them through securiety with the `macros` feature enabled, e.g. `securiety::curie!`,
+//! since the expanded code refers to securiety's types.
+
+mod patterns;
+
+use patterns::PATTERNS;
+use proc_macro::TokenStream;
+use quote::quote;
+use regex::Regex;
+use syn::{LitStr, parse_macro_input};
+
+/// Validates a CURIE literal at compile time and expands to a `securiety::Curie`.
+///
+/// See `securiety::curie!` for the documentation.
+#[proc_macro]
+pub fn curie(input: TokenStream) -> TokenStream {
+    let literal = parse_macro_input!(input as LitStr);
+    let curie = literal.value();
+
+    match check(&curie) {
+        Ok((prefix, reference)) => quote! {
+            ::securiety::Curie::from_parts(#prefix, #reference).expect("checked by curie!")
+        }
+        .into(),
+        Err(message) => syn::Error::new(literal.span(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+/// Checks a CURIE against the pattern of its ontology, returning its prefix and reference.
+///
+/// The checks mirror how securiety's `RegistryParser` diagnoses a CURIE, and the error
+/// messages match the `Display` output of its errors, except that unknown prefixes come
+/// without suggestions.
+fn check(curie: &str) -> Result<(&str, &str), String> {
+    let Some((prefix, reference)) = curie.split_once(':') else {
+        return Err(format!(
+            "Invalid CURIE: {curie} (missing ':' between prefix and reference)"
+        ));
+    };
+    let Some(&(expected, pattern, example, banana)) = PATTERNS
+        .iter()
+        .find(|(expected, ..)| expected.eq_ignore_ascii_case(prefix))
+    else {
+        return Err(format!("Unknown CURIE prefix: {prefix}"));
+    };
+
+    if reference.is_empty() {
+        return Err(format!("Invalid CURIE: {curie} (empty reference)"));
+    }
+    if let Some((offset, character)) = reference
+        .char_indices()
+        .find(|(_, character)| character.is_whitespace())
+    {
+        let offset = prefix.len() + 1 + offset;
+        return Err(format!(
+            "Invalid CURIE: {curie} (illegal character {character:?} at byte {offset})"
+        ));
+    }
+    if prefix != expected {
+        return Err(format!(
+            "Invalid CURIE: {curie} (expected prefix `{expected}`, got `{prefix}`)"
+        ));
+    }
+    let regex = Regex::new(pattern).expect("generated patterns are valid regexes");
+    if !regex.is_match(curie) {
+        if let Some(banana) = banana.filter(|&banana| reference.starts_with(banana)) {
+            return Err(format!(
+                "Invalid CURIE: {curie} (reference starts with redundant `{banana}`)"
+            ));
+        }
+        let example = example
+            .map(|example| format!(", e.g. `{example}`"))
+            .unwrap_or_default();
+        return Err(format!(
+            "Invalid CURIE: {curie} (reference does not match `{pattern}`{example})"
+        ));
+    }
+
+    Ok((prefix, reference))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_valid_curies() {
+        assert_eq!(check("HP:0000118"), Ok(("HP", "0000118")));
+        assert_eq!(check("FBbt:00000001"), Ok(("FBbt", "00000001")));
+    }
+
+    #[test]
+    fn test_rejects_invalid_curies() {
+        assert_eq!(
+            check("HP0000118").unwrap_err(),
+            "Invalid CURIE: HP0000118 (missing ':' between prefix and reference)"
+        );
+        assert_eq!(
+            check("HPO:0000118").unwrap_err(),
+            "Unknown CURIE prefix: HPO"
+        );
+        assert_eq!(
+            check("hp:0000118").unwrap_err(),
+            "Invalid CURIE: hp:0000118 (expected prefix `HP`, got `hp`)"
+        );
+        assert_eq!(
+            check("HP:").unwrap_err(),
+            "Invalid CURIE: HP: (empty reference)"
+        );
+        assert_eq!(
+            check("HP:000 118").unwrap_err(),
+            "Invalid CURIE: HP:000 118 (illegal character ' ' at byte 6)"
+        );
+        assert_eq!(
+            check("HP:HP:0000118").unwrap_err(),
+            "Invalid CURIE: HP:HP:0000118 (reference starts with redundant `HP:`)"
+        );
+        assert_eq!(
+            check("HP:000118").unwrap_err(),
+            "Invalid CURIE: HP:000118 (reference does not match `^HP:\\d{7}$`, e.g. `0000118`)"
+        );
+    }
+}
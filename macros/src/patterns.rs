@@ -0,0 +1,291 @@
+//! CURIE patterns of the ontologies in the Bioregistry, checked by `curie!`.
+//!
+//! @generated by `cargo xtask codegen` from `data/bioregistry.json` (no recorded Bioregistry release). Do not edit by hand.
+
+/// The prefix, pattern, example reference and banana of every supported ontology.
+#[rustfmt::skip]
+pub(crate) const PATTERNS: &[(&str, &str, Option<&str>, Option<&str>)] = &[
+    ("ADCAD", "^ADCAD:\\d{5}$", None, None),
+    ("ADO", "^ADO:\\d{7}$", None, None),
+    ("AEO", "^AEO:\\d{7}$", None, None),
+    ("AERO", "^AERO:\\d{7}$", None, None),
+    ("AGRO", "^AGRO:\\d{8}$", None, None),
+    ("AGROVOC", "^AGROVOC:[a-z0-9]+$", None, None),
+    ("AISM", "^AISM:\\d{7}$", None, None),
+    ("AMPHX", "^AMPHX:\\d+$", None, None),
+    ("APO", "^APO:\\d{7}$", None, None),
+    ("APOLLO_SV", "^APOLLO_SV:\\d{8}$", None, None),
+    ("ARO", "^ARO:\\d{7}$", None, None),
+    ("ATO", "^ATO:\\d{7}$", None, None),
+    ("ATOL", "^ATOL:\\d{7}$", None, None),
+    ("BCGO", "^BCGO:\\d{7}$", None, None),
+    ("BCIO", "^BCIO:\\d{6}$", None, None),
+    ("BCO", "^BCO:\\d{7}$", None, None),
+    ("BERVO", "^BERVO:\\d{7}$", None, None),
+    ("BFO", "^BFO:\\d{7}$", Some("0000001"), None),
+    ("BIRNLEX", "^BIRNLEX:\\d+$", None, None),
+    ("BKO", "^BKO:\\d+$", None, None),
+    ("BSPO", "^BSPO:\\d{7}$", None, None),
+    ("BTO", "^BTO:\\d{7}$", None, Some("BTO:")),
+    ("CAO", "^CAO:\\d+$", None, None),
+    ("CARO", "^CARO:\\d{7}$", None, None),
+    ("CCF", "^CCF:\\S+$", None, None),
+    ("CCO", "^CCO:\\w+$", None, None),
+    ("CDAO", "^CDAO:\\d{7}$", None, None),
+    ("CDNO", "^CDNO:\\d{7}$", None, None),
+    ("CEPH", "^CEPH:\\d{7}$", None, None),
+    ("CHEBI", "^CHEBI:\\d+$", Some("24431"), Some("CHEBI:")),
+    ("CHEMINF", "^CHEMINF:\\d{6}$", None, None),
+    ("CHMO", "^CHMO:\\d{7}$", None, None),
+    ("CIDO", "^CIDO:\\d{7}$", None, None),
+    ("CIO", "^CIO:\\d{7}$", None, None),
+    ("CL", "^CL:\\d{7}$", Some("0000000"), Some("CL:")),
+    ("CLAO", "^CLAO:\\d{7}$", None, None),
+    ("CLO", "^CLO:\\d{7}$", None, None),
+    ("CLYH", "^CLYH:\\d+$", None, None),
+    ("CMECS", "^CMECS:\\d+$", None, None),
+    ("CMO", "^CMO:\\d{7}$", None, None),
+    ("CMPO", "^CMPO:\\d{7}$", None, None),
+    ("COB", "^COB:\\d{7}$", None, None),
+    ("COLAO", "^COLAO:\\d{7}$", None, None),
+    ("CPT", "^CPT:\\d+$", None, None),
+    ("CRO", "^CRO:\\d{7}$", None, None),
+    ("CRYOEM", "^CRYOEM:\\d{7}$", None, None),
+    ("CTCAE", "^CTCAE:E\\d+$", None, None),
+    ("CTENO", "^CTENO:\\d{7}$", None, None),
+    ("CTO", "^CTO:\\d{7}$", None, None),
+    ("CVDO", "^CVDO:\\d{7}$", None, None),
+    ("DCM", "^DCM:\\d+$", None, None),
+    ("DDANAT", "^DDANAT:\\d{7}$", None, None),
+    ("DDPHENO", "^DDPHENO:\\d{7}$", None, None),
+    ("DERMO", "^DERMO:\\d{7}$", None, None),
+    ("DIDEO", "^DIDEO:\\d{8}$", None, None),
+    ("DISDRIV", "^DISDRIV:\\d+$", None, None),
+    ("DOID", "^DOID:\\d+$", Some("4"), Some("DOID:")),
+    ("DRON", "^DRON:\\d{8}$", None, None),
+    ("DrugCentral", "^DrugCentral:\\d+$", Some("307"), None),
+    ("DTO", "^DTO:\\d+$", None, None),
+    ("DUO", "^DUO:\\d{7}$", None, None),
+    ("ECAO", "^ECAO:\\d{7}$", None, None),
+    ("ECG", "^ECG:\\d+$", None, None),
+    ("ECO", "^ECO:\\d{7}$", Some("0000000"), Some("ECO:")),
+    ("ECOCORE", "^ECOCORE:\\d+$", None, None),
+    ("ECSO", "^ECSO:\\d+$", None, None),
+    ("ECTO", "^ECTO:\\d{7}$", None, None),
+    ("EHDA", "^EHDA:\\d+$", None, None),
+    ("EHDAA", "^EHDAA:\\d+$", None, None),
+    ("EHDAA2", "^EHDAA2:\\d{7}$", None, None),
+    ("EMAP", "^EMAP:\\d+$", None, None),
+    ("EMAPA", "^EMAPA:\\d+$", None, None),
+    ("ENM", "^ENM:\\d+$", None, None),
+    ("ENVO", "^ENVO:\\d{7,8}$", Some("01000254"), None),
+    ("EOL", "^EOL:\\d{7}$", None, None),
+    ("EPIO", "^EPIO:\\d{7}$", None, None),
+    ("EPSO", "^EPSO:\\d{7}$", None, None),
+    ("ERO", "^ERO:\\d{7}$", None, None),
+    ("EUPATH", "^EUPATH:\\d{7}$", None, None),
+    ("ExO", "^ExO:\\d{7}$", None, None),
+    ("FAO", "^FAO:\\d{7}$", None, None),
+    ("FBbi", "^FBbi:\\d+$", None, None),
+    ("FBbt", "^FBbt:\\d{8}$", None, None),
+    ("FBcv", "^FBcv:\\d{7}$", None, None),
+    ("FBdv", "^FBdv:\\d{8}$", None, None),
+    ("FIDEO", "^FIDEO:\\d+$", None, None),
+    ("FIX", "^FIX:\\d{7}$", None, None),
+    ("FLOPO", "^FLOPO:\\d{7}$", None, None),
+    ("FLU", "^FLU:\\d{7}$", None, None),
+    ("FMA", "^FMA:\\d+$", None, Some("FMA:")),
+    ("FOBI", "^FOBI:\\d{4,6}$", None, None),
+    ("FOODON", "^FOODON:[0-9]{8}$", None, None),
+    ("FOVT", "^FOVT:\\d{7}$", None, None),
+    ("FYPO", "^FYPO:\\d{7}$", None, None),
+    ("GALLONT", "^GALLONT:\\d{7}$", None, None),
+    ("GAZ", "^GAZ:\\d{8}$", None, None),
+    ("GECKO", "^GECKO:\\d{7}$", None, None),
+    ("GENEPIO", "^GENEPIO:\\d{7}$", None, None),
+    ("GENO", "^GENO:\\d{7}$", None, None),
+    ("GEO", "^GEO:\\d{9}$", None, None),
+    ("GNO", "^GNO:(\\d{8}|(\\w+\\d+\\w+))$", None, None),
+    ("GO", "^GO:\\d{7}$", Some("0008150"), Some("GO:")),
+    ("GPML", "^GPML:[A-Za-z]+$", None, None),
+    ("GSSO", "^GSSO:\\d{6}$", None, None),
+    ("HANCESTRO", "^HANCESTRO:\\d{4}$", None, None),
+    ("HAO", "^HAO:\\d{7}$", None, None),
+    ("HGNC", "^HGNC:\\d{1,5}$", Some("5"), None),
+    ("HOIP", "^HOIP:\\d{7}$", None, None),
+    ("HOM", "^HOM:\\d{7}$", None, None),
+    ("HP", "^HP:\\d{7}$", Some("0000118"), Some("HP:")),
+    ("HsapDv", "^HsapDv:\\d{7}$", None, None),
+    ("HSO", "^HSO:\\d{7}$", None, None),
+    ("HTN", "^HTN:\\d{8}$", None, None),
+    ("ICD10", "^ICD10:(([XVI]+)|([A-Z][0-9]+((-[A-Z][0-9]+)|(\\.[0-9]))?))$", Some("C34"), None),
+    ("ICD10CM", "^ICD10CM:([A-Z][0-9][0-9AB]((-[A-Z][0-9][0-9AB])|(\\.[0-9A-KXZ]([0-9A-EXYZ]([0-9A-HX][0-59A-HJKMNP-S]?)?)?)?))$", Some("F45.21"), None),
+    ("ICD9CM", "^ICD9CM:(?:([\\dA-Z]\\d{2}(\\.\\d{1,3}|))|(\\d{2}(\\.\\d{1,2}|)))$", None, None),
+    ("ICEO", "^ICEO:\\d{7}(_\\d)?$", None, None),
+    ("ICF", "^ICF:\\d+$", None, None),
+    ("ICO", "^ICO:\\d{7}$", None, None),
+    ("IDO", "^IDO:\\d{7}$", None, None),
+    ("IDODEN", "^IDODEN:\\d{7}$", None, None),
+    ("IDOMAL", "^IDOMAL:(5?)\\d{7}$", None, None),
+    ("INO", "^INO:\\d{7}$", None, None),
+    ("ITO", "^ITO:\\d+$", None, None),
+    ("KISAO", "^KISAO:\\d+$", None, None),
+    ("LABO", "^LABO:\\d{7}$", None, None),
+    ("LBO", "^LBO:\\d{7}$", None, None),
+    ("LEPAO", "^LEPAO:\\d{7}$", None, None),
+    ("LOINC", "^LOINC:(\\d|\\w)+-\\d$", Some("26464-8"), None),
+    ("LPT", "^LPT:\\d+$", None, None),
+    ("MA", "^MA:\\d+$", None, Some("MA:")),
+    ("MAMO", "^MAMO:\\d{7}$", None, None),
+    ("MAT", "^MAT:\\d{7}$", None, None),
+    ("MAXO", "^MAXO:\\d{7}$", None, None),
+    ("MCO", "^MCO:\\d+$", None, None),
+    ("MCRO", "^MCRO:\\d{7}$", None, None),
+    ("MESH", "^MESH:(C|D|Q)\\d+$", Some("D009369"), None),
+    ("METPO", "^METPO:\\d+$", None, None),
+    ("MF", "^MF:\\d{7}$", None, None),
+    ("MFMO", "^MFMO:\\d{7}$", None, None),
+    ("MFO", "^MFO:\\d{7}$", None, None),
+    ("MFOEM", "^MFOEM:\\d{6}$", None, None),
+    ("MFOMD", "^MFOMD:\\d{7}$", None, None),
+    ("MI", "^MI:\\d{4}$", None, None),
+    ("MIAPA", "^MIAPA:\\d{7}$", None, None),
+    ("MICRO", "^MICRO:\\d{7}$", None, None),
+    ("MIRO", "^MIRO:\\d{8}$", None, None),
+    ("MIXS", "^MIXS:\\d{7}$", None, None),
+    ("MMO", "^MMO:\\d{7}$", None, None),
+    ("MmusDv", "^MmusDv:\\d{7}$", None, None),
+    ("MONDO", "^MONDO:\\d{7}$", Some("0000001"), None),
+    ("MOP", "^MOP:\\d{7}$", None, None),
+    ("MOSAIC", "^MOSAIC:\\d{8}$", None, None),
+    ("MP", "^MP:\\d{7}$", Some("0000001"), Some("MP:")),
+    ("MPATH", "^MPATH:\\d+$", None, None),
+    ("MPIO", "^MPIO:\\d{7}$", None, None),
+    ("MRO", "^MRO:\\d{7}$", None, None),
+    ("MS", "^MS:\\d{7}$", None, None),
+    ("MWO", "^MWO:\\d{7}$", None, None),
+    ("NANDO", "^NANDO:\\d+$", None, None),
+    ("NBO", "^NBO:\\d{7}$", None, None),
+    ("NCBITaxon", "^NCBITaxon:(?:(\\d+)|([a-zA-Z_]+))$", Some("9606"), None),
+    ("NCIT", "^NCIT:[CRPA]\\d+$", Some("C3224"), None),
+    ("NCRO", "^NCRO:\\d{7}$", None, None),
+    ("NDDF", "^NDDF:\\d{6}$", None, None),
+    ("NGBO", "^NGBO:\\d{7}$", None, None),
+    ("NIFSTD", "^NIFSTD:BAMSC\\d+$", None, None),
+    ("NMR", "^NMR:\\d+$", None, None),
+    ("NOMEN", "^NOMEN:\\d{7}$", None, None),
+    ("NPO", "^NPO:\\d+$", None, None),
+    ("OAE", "^OAE:\\d{7}$", None, None),
+    ("OARCS", "^OARCS:\\d{7}$", None, None),
+    ("OBA", "^OBA:(VT)?\\d{7}$", None, None),
+    ("OBCS", "^OBCS:\\d{7}$", None, None),
+    ("OBI", "^OBI:\\d{7}$", Some("0000070"), Some("OBI:")),
+    ("OBIB", "^OBIB:\\d{7}$", None, None),
+    ("OCCO", "^OCCO:\\d+$", None, None),
+    ("OGG", "^OGG:\\d+$", None, None),
+    ("OGI", "^OGI:\\d{7}$", None, None),
+    ("OGMS", "^OGMS:\\d{7}$", None, None),
+    ("OGSF", "^OGSF:\\d{7}$", None, None),
+    ("OHD", "^OHD:\\d{7}$", None, None),
+    ("OHMI", "^OHMI:\\d{7}$", None, None),
+    ("OHPI", "^OHPI:\\d+$", None, None),
+    ("OlatDv", "^OlatDv:\\d{7}$", None, None),
+    ("OMIABIS", "^OMIABIS:\\d{7}$", None, None),
+    ("OMIM", "^OMIM:\\d+$", Some("603903"), None),
+    ("OMO", "^OMO:\\d{7}$", None, None),
+    ("OMP", "^OMP:\\d{7}$", None, None),
+    ("OMRSE", "^OMRSE:\\d{8}$", None, None),
+    ("ONE", "^ONE:\\d{7}$", None, None),
+    ("ONS", "^ONS:\\d{7}$", None, None),
+    ("ONTOAVIDA", "^ONTOAVIDA:\\d{8}$", None, None),
+    ("ONTONEO", "^ONTONEO:\\d{8}$", None, None),
+    ("OOSTT", "^OOSTT:\\d{8}$", None, None),
+    ("OPB", "^OPB:\\d+$", None, None),
+    ("OPL", "^OPL:\\d{7}$", None, None),
+    ("OPMI", "^OPMI:\\d{7}$", None, None),
+    ("ORNASEQ", "^ORNASEQ:\\d{7}$", None, None),
+    ("ORDO", "^ORDO:C?\\d+$", Some("558"), None),
+    ("OVAE", "^OVAE:\\d{7}$", None, None),
+    ("PATO", "^PATO:\\d{7}$", Some("0000001"), Some("PATO:")),
+    ("PAV", "^PAV:[a-z][a-zA-Z]+$", None, None),
+    ("PCL", "^PCL:\\d{7}$", None, None),
+    ("PCO", "^PCO:\\d{7}$", None, None),
+    ("PDRO", "^PDRO:\\d{7}$", None, None),
+    ("PdumDv", "^PdumDv:\\d{7}$", None, None),
+    ("PECO", "^PECO:\\d{7}$", None, None),
+    ("PHENX", "^PHENX:\\d+$", None, None),
+    ("PHIPO", "^PHIPO:\\d{7}$", None, None),
+    ("PLANA", "^PLANA:\\d{7}$", None, None),
+    ("PLANP", "^PLANP:\\d+$", None, None),
+    ("PLOSTHES", "^PLOSTHES:\\d+$", None, None),
+    ("PO", "^PO:\\d+$", None, None),
+    ("PORO", "^PORO:\\d{7}$", None, None),
+    ("PPO", "^PPO:\\d{7}$", None, None),
+    ("PR", "^PR:(?:\\d{9}|[OPQ][0-9][A-Z0-9]{3}[0-9](?:-\\d+)?|[A-NR-Z][0-9](?:[A-Z][A-Z0-9]{2}[0-9]){1,2}(?:-\\d+)?)$", Some("000000001"), None),
+    ("PROCO", "^PROCO:\\d{7}$", None, None),
+    ("PSDO", "^PSDO:\\d{7}$", None, None),
+    ("PSO", "^PSO:\\d{7}$", None, None),
+    ("PW", "^PW:\\d{7}$", None, None),
+    ("RADLEX", "^RADLEX:RID\\d+$", None, None),
+    ("RBO", "^RBO:\\d{6,8}$", None, None),
+    ("REO", "^REO:\\d{7}$", None, None),
+    ("REX", "^REX:\\d{7}$", None, None),
+    ("RNAO", "^RNAO:\\d{7}$", None, None),
+    ("RO", "^RO:(HOM)?\\d{7}$", None, None),
+    ("ROLEO", "^ROLEO:\\d{7}$", None, None),
+    ("RS", "^RS:\\d{7}$", None, None),
+    ("RXNO", "^RXNO:\\d{7}$", None, None),
+    ("RXNORM", "^RXNORM:[0-9]{1,7}$", Some("221058"), None),
+    ("SALMON", "^SALMON:\\d+$", None, None),
+    ("SAO", "^SAO:\\d+$", None, None),
+    ("SASAP", "^SASAP:\\d+$", None, None),
+    ("SBO", "^SBO:\\d{7}$", None, None),
+    ("SCDO", "^SCDO:\\d{7}$", None, None),
+    ("SDGIO", "^SDGIO:\\d{8}$", None, None),
+    ("SENSO", "^SENSO:\\d+$", None, None),
+    ("SEP", "^SEP:\\d{5,6}$", None, None),
+    ("SEPIO", "^SEPIO:\\d{7}$", None, None),
+    ("SIBO", "^SIBO:\\d{7}$", None, None),
+    ("SIO", "^SIO:\\d{6}$", None, None),
+    ("SO", "^SO:\\d{7}$", Some("0000704"), Some("SO:")),
+    ("SPD", "^SPD:\\d{7}$", None, None),
+    ("STATO", "^STATO:\\d{7}$", None, None),
+    ("STY", "^STY:T\\d{3}$", None, None),
+    ("SWO", "^SWO:\\d{7,8}$", None, None),
+    ("SYMP", "^SYMP:\\d{7}$", None, None),
+    ("T4FS", "^T4FS:\\d{7}$", None, None),
+    ("TADS", "^TADS:\\d{7}$", None, None),
+    ("TAO", "^TAO:\\d{7}$", None, None),
+    ("TAXRANK", "^TAXRANK:\\d{7}$", None, None),
+    ("TEDDY", "^TEDDY:\\d+$", None, None),
+    ("TGMA", "^TGMA:\\d{7}$", None, None),
+    ("TO", "^TO:\\d{7}$", None, None),
+    ("TRANS", "^TRANS:\\d{7}$", None, None),
+    ("TTO", "^TTO:\\d+$", None, None),
+    ("TXPO", "^TXPO:\\d{7}$", None, None),
+    ("UBERON", "^UBERON:\\d+$", Some("0000061"), Some("UBERON:")),
+    ("UO", "^UO:\\d{7}$", None, None),
+    ("UPA", "^UPA:(UCR|UCY|UER|ULS|UPA|UPC|UPX)\\d{5}$", None, None),
+    ("VANDF", "^VANDF:\\d+$", None, None),
+    ("VariO", "^VariO:\\d+$", None, None),
+    ("VBO", "^VBO:\\d{7}$", None, None),
+    ("VHOG", "^VHOG:\\d{7}$", None, None),
+    ("VIDO", "^VIDO:\\d{7}$", None, None),
+    ("VO", "^VO:\\d{7}$", None, None),
+    ("VSAO", "^VSAO:\\d{7}$", None, None),
+    ("VSO", "^VSO:\\d{7}$", None, None),
+    ("VT", "^VT:\\d{7}$", None, None),
+    ("VTO", "^VTO:\\d{7}$", None, None),
+    ("WBbt", "^WBbt:\\d{7}$", None, None),
+    ("WBls", "^WBls:\\d{7}$", None, None),
+    ("WBPhenotype", "^WBPhenotype:\\d{7}$", None, None),
+    ("XAO", "^XAO:\\d{7}$", None, None),
+    ("XCO", "^XCO:\\d{7}$", None, None),
+    ("XLMOD", "^XLMOD:\\d{5}$", None, None),
+    ("XPO", "^XPO:\\d+$", None, None),
+    ("ZEA", "^ZEA:\\d{7}$", None, None),
+    ("ZECO", "^ZECO:\\d{7}$", None, None),
+    ("ZFA", "^ZFA:\\d{7}$", None, None),
+    ("ZFS", "^ZFS:\\d{7}$", None, None),
+    ("ZP", "^ZP:\\d+$", None, None),
+];
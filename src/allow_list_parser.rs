@@ -0,0 +1,201 @@
+use crate::curie::{Curie, CurieRef};
+use crate::error::{CurieParsingError, UnknownPrefixError};
+use crate::normalization::PrefixNormalization;
+use crate::ontology::Ontology;
+use crate::registry_parser::Routed;
+use crate::traits::CurieParsing;
+
+/// A parser that only accepts CURIEs of a fixed set of ontologies.
+///
+/// A CURIE is accepted if its prefix refers to one of the allowed ontologies and it matches
+/// the pattern of that ontology. CURIEs of any other ontology are rejected with
+/// [`CurieParsingError::PrefixNotAllowed`], which lists the allowed prefixes. Prefixes are
+/// normalized and routed like by a [`RegistryParser`](crate::RegistryParser).
+///
+/// # Examples
+///
+/// ```
+/// use securiety::{CurieParser, CurieParsing, CurieParsingError};
+///
+/// let parser = CurieParser::any_of(&["MONDO", "OMIM", "ORDO"]).unwrap();
+/// assert!(parser.parse("MONDO:0006007").is_ok());
+/// assert!(parser.parse("OMIM:603903").is_ok());
+///
+/// match parser.parse("HP:0000118") {
+///     Err(CurieParsingError::PrefixNotAllowed { allowed, .. }) => {
+///         assert_eq!(allowed, ["MONDO", "OMIM", "ORDO"]);
+///     }
+///     result => panic!("Expected PrefixNotAllowed error, got {:?}", result),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllowListParser {
+    ontologies: Vec<Ontology>,
+    prefix_normalization: PrefixNormalization,
+}
+
+impl AllowListParser {
+    /// Creates a parser that accepts CURIEs of the given ontologies.
+    ///
+    /// Duplicates are removed, while the order of the ontologies is kept.
+    pub fn new(ontologies: impl IntoIterator<Item = Ontology>) -> AllowListParser {
+        let mut allowed = Vec::new();
+        for ontology in ontologies {
+            if !allowed.contains(&ontology) {
+                allowed.push(ontology);
+            }
+        }
+        AllowListParser {
+            ontologies: allowed,
+            prefix_normalization: PrefixNormalization::Exact,
+        }
+    }
+
+    /// Creates a parser that accepts CURIEs of the ontologies with the given prefixes.
+    ///
+    /// Prefixes are resolved like [`Ontology::from_prefix`]. Returns an error for the first
+    /// prefix that has no generated validator.
+    pub fn from_prefixes(prefixes: &[&str]) -> Result<AllowListParser, UnknownPrefixError> {
+        let ontologies = prefixes
+            .iter()
+            .map(|prefix| {
+                Ontology::from_prefix(prefix).ok_or_else(|| UnknownPrefixError::new(prefix))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(AllowListParser::new(ontologies))
+    }
+
+    /// Sets how the prefix of a CURIE is normalized before it is routed and validated.
+    ///
+    /// With [`PrefixNormalization::Synonyms`], CURIEs with a synonym prefix are accepted if
+    /// the ontology of the synonym is allowed, e.g. `MIM:603903` if OMIM is.
+    pub fn with_prefix_normalization(mut self, prefix_normalization: PrefixNormalization) -> Self {
+        self.prefix_normalization = prefix_normalization;
+        self
+    }
+
+    /// The ontologies accepted by this parser.
+    pub fn ontologies(&self) -> &[Ontology] {
+        &self.ontologies
+    }
+
+    pub fn prefix_normalization(&self) -> PrefixNormalization {
+        self.prefix_normalization
+    }
+
+    /// The preferred prefixes of the ontologies accepted by this parser.
+    pub fn allowed_prefixes(&self) -> Vec<&'static str> {
+        self.ontologies
+            .iter()
+            .map(|ontology| ontology.prefix())
+            .collect()
+    }
+}
+
+impl FromIterator<Ontology> for AllowListParser {
+    fn from_iter<I: IntoIterator<Item = Ontology>>(iter: I) -> Self {
+        AllowListParser::new(iter)
+    }
+}
+
+impl CurieParsing for AllowListParser {
+    /// Parses a CURIE with the validator of the allowed ontology its prefix refers to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Curie)` - Successfully parsed CURIE
+    /// * `Err(CurieParsingError::InvalidCurie)` - The input has no colon, or failed validation
+    /// * `Err(CurieParsingError::PrefixNotAllowed)` - The prefix does not refer to one of the
+    ///   allowed ontologies
+    fn parse(&self, curie: &str) -> Result<Curie, CurieParsingError> {
+        self.parse_ref(curie).map(CurieRef::to_owned)
+    }
+
+    fn parse_ref<'a>(&self, curie: &'a str) -> Result<CurieRef<'a>, CurieParsingError> {
+        let routed = Routed::new(curie, self.prefix_normalization)?;
+        let ontology = routed
+            .ontology()
+            .filter(|ontology| self.ontologies.contains(ontology))
+            .ok_or_else(|| CurieParsingError::PrefixNotAllowed {
+                curie: curie.to_string(),
+                allowed: self.allowed_prefixes(),
+            })?;
+        routed.validate(ontology)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::InvalidCurieReason;
+
+    #[test]
+    fn test_accepts_allowed_ontologies() {
+        let parser = AllowListParser::new([Ontology::Mondo, Ontology::Omim]);
+
+        assert_eq!(
+            parser.parse("MONDO:0006007").unwrap(),
+            Curie::new("MONDO", "0006007")
+        );
+        assert!(parser.parse("OMIM:603903").is_ok());
+    }
+
+    #[test]
+    fn test_rejects_other_ontologies() {
+        let parser = AllowListParser::new([Ontology::Mondo, Ontology::Omim]);
+
+        for curie in ["HP:0000118", "custom:123"] {
+            match parser.parse(curie) {
+                Err(CurieParsingError::PrefixNotAllowed { curie: s, allowed }) => {
+                    assert_eq!(s, curie);
+                    assert_eq!(allowed, ["MONDO", "OMIM"]);
+                }
+                result => panic!("Expected PrefixNotAllowed error, got {:?}", result),
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_invalid_reference() {
+        let parser = AllowListParser::new([Ontology::Mondo]);
+
+        assert!(matches!(
+            parser.parse("MONDO:6007"),
+            Err(CurieParsingError::InvalidCurie {
+                reason: InvalidCurieReason::ReferenceMismatch { .. },
+                ..
+            })
+        ));
+        assert!(matches!(
+            parser.parse("MONDO0006007"),
+            Err(CurieParsingError::InvalidCurie {
+                reason: InvalidCurieReason::MissingSeparator,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_normalizes_prefix_before_routing() {
+        let parser = AllowListParser::new([Ontology::Omim])
+            .with_prefix_normalization(PrefixNormalization::Synonyms);
+
+        assert_eq!(
+            parser.parse("MIM:603903").unwrap(),
+            Curie::new("OMIM", "603903")
+        );
+        assert!(matches!(
+            AllowListParser::new([Ontology::Omim]).parse("MIM:603903"),
+            Err(CurieParsingError::PrefixNotAllowed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_from_prefixes() {
+        let parser = AllowListParser::from_prefixes(&["mondo", "OMIM", "MONDO"]).unwrap();
+        assert_eq!(parser.ontologies(), [Ontology::Mondo, Ontology::Omim]);
+
+        let error = AllowListParser::from_prefixes(&["MONDO", "custom"]).unwrap_err();
+        assert_eq!(error.prefix(), "custom");
+    }
+}
@@ -0,0 +1,123 @@
+use crate::ontology::Ontology;
+use regex::RegexSet;
+use std::sync::OnceLock;
+
+/// Finds the ontologies whose pattern matches a CURIE or reference.
+///
+/// A CURIE is classified by its prefix: the patterns of all ontologies start with their
+/// preferred prefix, so only the ontology of the prefix can match, and only its pattern is
+/// checked. A bare reference can match many ontologies, so the reference patterns of all
+/// ontologies are compiled into a single [`RegexSet`] and matched in one pass. Compiling the
+/// set takes tens of milliseconds and several megabytes, so it happens on the first call of
+/// [`classify_reference`](Self::classify_reference); create one classifier and reuse it.
+///
+/// # Examples
+///
+/// ```
+/// use securiety::{CurieClassifier, Ontology};
+/// let classifier = CurieClassifier::new();
+///
+/// assert_eq!(classifier.classify("HP:0000118"), [Ontology::Hp]);
+/// assert!(classifier.classify("HP:118").is_empty());
+///
+/// // References without a prefix match every ontology that accepts them.
+/// let candidates = classifier.classify_reference("0000118");
+/// assert!(candidates.contains(&Ontology::Hp));
+/// assert!(candidates.contains(&Ontology::Mondo));
+/// assert!(!candidates.contains(&Ontology::Mesh));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CurieClassifier {
+    references: OnceLock<RegexSet>,
+}
+
+impl CurieClassifier {
+    pub fn new() -> Self {
+        CurieClassifier::default()
+    }
+
+    /// The ontologies whose pattern matches the CURIE, in the order of [`Ontology::ALL`].
+    ///
+    /// Patterns are anchored to the preferred prefix, so at most the ontology of the prefix
+    /// matches.
+    pub fn classify(&self, curie: &str) -> Vec<Ontology> {
+        let Some((prefix, _)) = curie.split_once(':') else {
+            return Vec::new();
+        };
+        Ontology::from_prefix(prefix)
+            .filter(|ontology| ontology.prefix() == prefix && ontology.regex().is_match(curie))
+            .into_iter()
+            .collect()
+    }
+
+    /// The ontologies whose pattern accepts the reference, in the order of [`Ontology::ALL`].
+    ///
+    /// The first call compiles the reference patterns of all ontologies.
+    pub fn classify_reference(&self, reference: &str) -> Vec<Ontology> {
+        self.references
+            .get_or_init(compile_references)
+            .matches(reference)
+            .into_iter()
+            .map(|index| Ontology::ALL[index])
+            .collect()
+    }
+}
+
+/// Compiles the patterns of all ontologies, without their prefix, into a set.
+fn compile_references() -> RegexSet {
+    let patterns = Ontology::ALL.iter().map(|ontology| {
+        let metadata = ontology.metadata();
+        let reference = metadata
+            .pattern()
+            .strip_prefix('^')
+            .and_then(|pattern| pattern.strip_prefix(metadata.prefix()))
+            .and_then(|pattern| pattern.strip_prefix(':'))
+            .expect("generated patterns start with the prefix");
+        format!("^{reference}")
+    });
+    RegexSet::new(patterns).expect("generated patterns are valid regexes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_matches_validators() {
+        let classifier = CurieClassifier::new();
+
+        for curie in ["HP:0000118", "MONDO:0006007", "hp:0000118", "HP:HP:1", "x"] {
+            let expected: Vec<_> = Ontology::ALL
+                .iter()
+                .copied()
+                .filter(|ontology| ontology.regex().is_match(curie))
+                .collect();
+            assert_eq!(classifier.classify(curie), expected, "{curie}");
+        }
+    }
+
+    #[test]
+    fn test_classify_does_not_compile_references() {
+        let classifier = CurieClassifier::new();
+
+        assert_eq!(classifier.classify("HP:0000118"), [Ontology::Hp]);
+        assert!(classifier.references.get().is_none());
+    }
+
+    #[test]
+    fn test_classify_reference() {
+        let classifier = CurieClassifier::new();
+
+        assert!(
+            classifier
+                .classify_reference("D009369")
+                .contains(&Ontology::Mesh)
+        );
+        assert!(
+            !classifier
+                .classify_reference("0000118")
+                .contains(&Ontology::Mesh)
+        );
+        assert!(classifier.classify_reference("not a reference").is_empty());
+    }
+}
@@ -1,4 +1,13 @@
+use crate::curie_parser::CurieParser;
+use crate::error::{CurieParsingError, InvalidCurieReason};
+use crate::natural::cmp_natural;
+use crate::ontology::Ontology;
+use crate::prefix_map::PrefixMap;
+use crate::registry_parser::{RegistryParser, UnknownPrefixPolicy};
+use crate::traits::CurieParsing;
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 /// A parsed CURIE (Compact URI) representation.
 ///
@@ -37,6 +46,37 @@ impl Curie {
         Curie { inner, prefix_len }
     }
 
+    /// Creates a `Curie` from a prefix and reference, validating it like a
+    /// [`RegistryParser`] with [`UnknownPrefixPolicy::General`]: against the pattern of the
+    /// ontology the prefix refers to, or the general rules for other prefixes.
+    ///
+    /// ```
+    /// use securiety::{Curie, CurieParsingError};
+    ///
+    /// let curie = Curie::from_parts("HP", "0000118").unwrap();
+    /// assert_eq!(curie.to_string(), "HP:0000118");
+    ///
+    /// assert!(Curie::from_parts("custom", "42").is_ok());
+    /// assert!(matches!(
+    ///     Curie::from_parts("HP", "118"),
+    ///     Err(CurieParsingError::InvalidCurie { .. })
+    /// ));
+    /// ```
+    pub fn from_parts(prefix: &str, reference: &str) -> Result<Curie, CurieParsingError> {
+        let curie = Curie::new(prefix, reference);
+        if let Some(offset) = prefix.find(':') {
+            return Err(CurieParsingError::InvalidCurie {
+                curie: curie.inner,
+                reason: InvalidCurieReason::IllegalCharacter {
+                    offset,
+                    character: ':',
+                },
+            });
+        }
+        RegistryParser::new(UnknownPrefixPolicy::General).parse_ref(&curie.inner)?;
+        Ok(curie)
+    }
+
     pub fn prefix(&self) -> &str {
         &self.inner[..self.prefix_len]
     }
@@ -44,6 +84,70 @@ impl Curie {
     pub fn reference(&self) -> &str {
         &self.inner[self.prefix_len + 1..]
     }
+
+    /// The reference as a number, or `None` if it is not a number that fits a `u64`.
+    ///
+    /// Leading zeros are ignored, so the number does not round-trip for zero-padded
+    /// references; see [`Ontology::curie_from_number`](crate::Ontology::curie_from_number).
+    ///
+    /// ```
+    /// use securiety::{CurieParser, CurieParsing};
+    ///
+    /// let curie = CurieParser::hp().parse("HP:0000118").unwrap();
+    /// assert_eq!(curie.reference_as_u64(), Some(118));
+    ///
+    /// let curie = CurieParser::mesh().parse("MESH:D009369").unwrap();
+    /// assert_eq!(curie.reference_as_u64(), None);
+    /// ```
+    pub fn reference_as_u64(&self) -> Option<u64> {
+        let reference = self.reference();
+        match reference.bytes().all(|b| b.is_ascii_digit()) {
+            true => reference.parse().ok(),
+            false => None,
+        }
+    }
+
+    /// Expands this CURIE into its full IRI using the given [`PrefixMap`].
+    ///
+    /// Returns `None` if the prefix map has no URI prefix for this CURIE's prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use securiety::{CurieParser, CurieParsing, PrefixMap};
+    /// let curie = CurieParser::mondo().parse("MONDO:0006007").unwrap();
+    ///
+    /// assert_eq!(
+    ///     curie.expand(&PrefixMap::bioregistry()).as_deref(),
+    ///     Some("http://purl.obolibrary.org/obo/MONDO_0006007")
+    /// );
+    /// ```
+    pub fn expand(&self, prefix_map: &PrefixMap) -> Option<String> {
+        prefix_map.expand(self)
+    }
+
+    /// Compares CURIEs by prefix, then by reference with runs of digits compared by their
+    /// numeric value, so that `CHEBI:9` comes before `CHEBI:10`.
+    ///
+    /// The derived [`Ord`] compares the string form instead, which only sorts numerically if
+    /// the references are zero-padded. See [`NaturalOrd`](crate::NaturalOrd) to sort
+    /// collections this way.
+    ///
+    /// ```
+    /// use securiety::{CurieParser, CurieParsing};
+    /// use std::cmp::Ordering;
+    /// let parser = CurieParser::ncbitaxon();
+    ///
+    /// let human = parser.parse("NCBITaxon:9606").unwrap();
+    /// let mouse = parser.parse("NCBITaxon:10090").unwrap();
+    /// assert_eq!(human.cmp_natural(&mouse), Ordering::Less);
+    /// assert_eq!(human.cmp(&mouse), Ordering::Greater);
+    /// ```
+    pub fn cmp_natural(&self, other: &Curie) -> Ordering {
+        self.prefix()
+            .cmp(other.prefix())
+            .then_with(|| cmp_natural(self.reference(), other.reference()))
+    }
 }
 
 impl Display for Curie {
@@ -58,6 +162,182 @@ impl AsRef<str> for Curie {
     }
 }
 
+/// Parses a CURIE, validating it like [`CurieParser::general`](crate::CurieParser::general).
+///
+/// ```
+/// use securiety::Curie;
+///
+/// let curie: Curie = "HP:0000118".parse().unwrap();
+/// assert_eq!(curie.prefix(), "HP");
+/// assert!("HP 0000118".parse::<Curie>().is_err());
+/// ```
+impl FromStr for Curie {
+    type Err = CurieParsingError;
+
+    fn from_str(curie: &str) -> Result<Self, Self::Err> {
+        CurieParser::general().parse(curie)
+    }
+}
+
+impl TryFrom<&str> for Curie {
+    type Error = CurieParsingError;
+
+    fn try_from(curie: &str) -> Result<Self, Self::Error> {
+        curie.parse()
+    }
+}
+
+/// Validates a CURIE like [`CurieParser::general`](crate::CurieParser::general), keeping the
+/// allocation of the string.
+impl TryFrom<String> for Curie {
+    type Error = CurieParsingError;
+
+    fn try_from(curie: String) -> Result<Self, Self::Error> {
+        let prefix_len = CurieParser::general().parse_ref(&curie)?.prefix().len();
+        Ok(Curie {
+            inner: curie,
+            prefix_len,
+        })
+    }
+}
+
+impl PartialEq<CurieRef<'_>> for Curie {
+    fn eq(&self, other: &CurieRef<'_>) -> bool {
+        self.prefix() == other.prefix() && self.reference() == other.reference()
+    }
+}
+
+/// A CURIE that borrows its prefix and reference instead of owning them.
+///
+/// Returned by [`CurieParsing::parse_ref`], which avoids allocating a [`Curie`] for every
+/// parsed CURIE, e.g. when validating the rows of a large file. The reference is always
+/// borrowed from the input. The prefix is borrowed from the input, or from the Bioregistry
+/// metadata if the parser replaced it by a preferred prefix.
+///
+/// # Examples
+///
+/// ```
+/// use securiety::{CurieParser, CurieParsing};
+/// let parser = CurieParser::hp();
+///
+/// let line = String::from("HP:0000118");
+/// let curie = parser.parse_ref(&line).unwrap();
+/// assert_eq!(curie.prefix(), "HP");
+/// assert_eq!(curie.reference(), "0000118");
+///
+/// let owned = curie.to_owned();
+/// assert_eq!(owned, parser.parse(&line).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
+pub struct CurieRef<'a> {
+    prefix: &'a str,
+    reference: &'a str,
+}
+
+impl<'a> CurieRef<'a> {
+    pub(crate) fn new(prefix: &'a str, reference: &'a str) -> CurieRef<'a> {
+        CurieRef { prefix, reference }
+    }
+
+    /// Borrows the prefix and reference of a CURIE that was parsed from `input`.
+    ///
+    /// Returns `None` if the parser changed the input beyond replacing its prefix by the
+    /// preferred prefix of a supported ontology.
+    pub(crate) fn borrow_parsed(input: &'a str, curie: &Curie) -> Option<CurieRef<'a>> {
+        let (prefix, reference) = input.split_once(':')?;
+        if reference != curie.reference() {
+            return None;
+        }
+        if prefix == curie.prefix() {
+            return Some(CurieRef::new(prefix, reference));
+        }
+        Ontology::from_prefix(curie.prefix())
+            .map(Ontology::prefix)
+            .filter(|&preferred| preferred == curie.prefix())
+            .map(|preferred| CurieRef::new(preferred, reference))
+    }
+
+    pub fn prefix(&self) -> &'a str {
+        self.prefix
+    }
+
+    pub fn reference(&self) -> &'a str {
+        self.reference
+    }
+
+    /// Copies the prefix and reference into an owned [`Curie`].
+    pub fn to_owned(self) -> Curie {
+        Curie::new(self.prefix, self.reference)
+    }
+}
+
+impl Display for CurieRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.prefix, self.reference)
+    }
+}
+
+impl Ord for CurieRef<'_> {
+    /// Orders like the string form of the CURIEs, like [`Curie`], e.g. `a-b:1` before `a:1`.
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Prefixes contain no colon, so the CURIEs differ before either reference starts.
+        let prefix = self.prefix.bytes().chain(Some(b':'));
+        prefix
+            .cmp(other.prefix.bytes().chain(Some(b':')))
+            .then_with(|| self.reference.cmp(other.reference))
+    }
+}
+
+impl PartialOrd for CurieRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> From<&'a Curie> for CurieRef<'a> {
+    fn from(curie: &'a Curie) -> Self {
+        CurieRef::new(curie.prefix(), curie.reference())
+    }
+}
+
+impl From<CurieRef<'_>> for Curie {
+    fn from(curie: CurieRef<'_>) -> Self {
+        curie.to_owned()
+    }
+}
+
+impl PartialEq<Curie> for CurieRef<'_> {
+    fn eq(&self, other: &Curie) -> bool {
+        other == self
+    }
+}
+
+/// Serializes the CURIE as its string form, e.g. `"HP:0000054"`.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Curie {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.inner)
+    }
+}
+
+/// Deserializes a CURIE from its string form, validating it like
+/// [`CurieParser::general`](crate::CurieParser::general).
+///
+/// ```
+/// # use securiety::Curie;
+/// let curie: Curie = serde_json::from_str(r#""HP:0000054""#).unwrap();
+/// assert_eq!(curie.prefix(), "HP");
+///
+/// assert!(serde_json::from_str::<Curie>(r#""HP 0000054""#).is_err());
+/// ```
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Curie {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let curie = String::deserialize(deserializer)?;
+        Curie::try_from(curie).map_err(serde::de::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,6 +381,26 @@ mod tests {
         assert_eq!(s, "ex:ref");
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let curie = Curie::new("HP", "0000054");
+
+        let json = serde_json::to_string(&curie).unwrap();
+        assert_eq!(json, r#""HP:0000054""#);
+        assert_eq!(serde_json::from_str::<Curie>(&json).unwrap(), curie);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_rejects_invalid_curies() {
+        for json in [r#""HP0000054""#, r#""HP: 0000054""#, "54"] {
+            assert!(serde_json::from_str::<Curie>(json).is_err(), "{json}");
+        }
+        let error = serde_json::from_str::<Curie>(r#""HP0000054""#).unwrap_err();
+        assert!(error.to_string().starts_with("Invalid CURIE: HP0000054"));
+    }
+
     #[test]
     fn test_long_prefix_and_reference() {
         let long_prefix = "a".repeat(1000);
@@ -110,4 +410,125 @@ mod tests {
         assert_eq!(curie.prefix(), long_prefix);
         assert_eq!(curie.reference(), long_reference);
     }
+
+    #[test]
+    fn test_curie_ref_converts_to_curie() {
+        let curie = Curie::new("HP", "0000118");
+        let curie_ref = CurieRef::from(&curie);
+
+        assert_eq!(curie_ref.to_string(), "HP:0000118");
+        assert_eq!(curie_ref, curie);
+        assert_eq!(curie_ref.to_owned(), curie);
+        assert_eq!(Curie::from(curie_ref), curie);
+    }
+
+    #[test]
+    fn test_curie_ref_orders_like_curie() {
+        let curies = [
+            ("a", "1"),
+            ("a-b", "1"),
+            ("ab", "1"),
+            ("a", "10"),
+            ("a", "2"),
+            ("A", "1"),
+            ("HP", "0000118"),
+        ];
+        for a in curies {
+            for b in curies {
+                assert_eq!(
+                    CurieRef::new(a.0, a.1).cmp(&CurieRef::new(b.0, b.1)),
+                    Curie::new(a.0, a.1).cmp(&Curie::new(b.0, b.1)),
+                    "{a:?} {b:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_borrow_parsed() {
+        let input = "hp:0000118";
+        let borrowed = CurieRef::borrow_parsed(input, &Curie::new("HP", "0000118")).unwrap();
+        assert_eq!(borrowed, CurieRef::new("HP", "0000118"));
+        assert!(std::ptr::eq(borrowed.reference(), &input[3..]));
+
+        let custom = CurieRef::borrow_parsed("ex:1", &Curie::new("ex", "1"));
+        assert_eq!(custom, Some(CurieRef::new("ex", "1")));
+        assert_eq!(
+            CurieRef::borrow_parsed("ex:1", &Curie::new("EX", "1")),
+            None
+        );
+        assert_eq!(
+            CurieRef::borrow_parsed("HP_0000118", &Curie::new("HP", "0000118")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reference_as_u64() {
+        assert_eq!(Curie::new("HP", "0000118").reference_as_u64(), Some(118));
+        assert_eq!(
+            Curie::new("ex", "18446744073709551615").reference_as_u64(),
+            Some(u64::MAX)
+        );
+        assert_eq!(
+            Curie::new("ex", "18446744073709551616").reference_as_u64(),
+            None
+        );
+        assert_eq!(Curie::new("ex", "+1").reference_as_u64(), None);
+        assert_eq!(Curie::new("ex", "1.5").reference_as_u64(), None);
+    }
+
+    #[test]
+    fn test_from_parts_validates() {
+        assert_eq!(
+            Curie::from_parts("HP", "0000118").unwrap(),
+            Curie::new("HP", "0000118")
+        );
+        assert_eq!(
+            Curie::from_parts("custom", "ref:with:colons").unwrap(),
+            Curie::new("custom", "ref:with:colons")
+        );
+        assert!(Curie::from_parts("custom", "with space").is_err());
+
+        match Curie::from_parts("a:b", "c") {
+            Err(CurieParsingError::InvalidCurie { curie, reason }) => {
+                assert_eq!(curie, "a:b:c");
+                assert_eq!(
+                    reason,
+                    InvalidCurieReason::IllegalCharacter {
+                        offset: 1,
+                        character: ':'
+                    }
+                );
+            }
+            result => panic!("Expected InvalidCurie error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_try_from_string_keeps_allocation() {
+        let input = String::from("prefix:reference");
+        let pointer = input.as_ptr();
+
+        let curie = Curie::try_from(input).unwrap();
+        assert_eq!(curie.inner.as_ptr(), pointer);
+        assert_eq!(curie.prefix_len, 6);
+        assert!(Curie::try_from(String::from("no separator")).is_err());
+    }
+
+    #[test]
+    fn test_from_str_matches_general_parser() {
+        assert_eq!(
+            "HP:0000118".parse::<Curie>().unwrap(),
+            Curie::new("HP", "0000118")
+        );
+        assert_eq!(
+            Curie::try_from("custom:a:b").unwrap(),
+            Curie::new("custom", "a:b")
+        );
+        assert!(matches!(
+            Curie::try_from(":0000118"),
+            Err(CurieParsingError::InvalidCurie { .. })
+        ));
+    }
 }
@@ -1,6 +1,11 @@
-use crate::curie::Curie;
-use crate::error::CurieParsingError;
+use crate::allow_list_parser::AllowListParser;
+use crate::curie::{Curie, CurieRef};
+use crate::error::{CurieParsingError, UnknownPrefixError};
+use crate::metadata::OntologyMetadata;
+use crate::normalization::PrefixNormalization;
 use crate::traits::{CurieParsing, CurieValidation};
+use crate::validators::regex_validator::CurieRegexValidator;
+use std::borrow::Cow;
 
 /// A parser for CURIE (Compact URI) strings that validates input using a configurable validator.
 ///
@@ -25,24 +30,29 @@ use crate::traits::{CurieParsing, CurieValidation};
 #[derive(Debug, Clone)]
 pub struct CurieParser<Validator: CurieValidation> {
     pub(crate) validator: Validator,
+    pub(crate) prefix_normalization: PrefixNormalization,
 }
 
 impl<Validator: CurieValidation> CurieParsing for CurieParser<Validator> {
     /// Parses a CURIE string into a [`Curie`] instance.
     ///
     /// The parsing process:
-    /// 1. Validates the input string using the configured validator
-    /// 2. Splits the string on the colon (`:`) separator
-    /// 3. Returns a [`Curie`] with the prefix and reference components
+    /// 1. Normalizes the prefix, if a [`PrefixNormalization`] other than
+    ///    [`Exact`](PrefixNormalization::Exact) is set
+    /// 2. Validates the input string using the configured validator, see
+    ///    [`CurieValidation::explain`]
+    /// 3. Splits the string on the colon (`:`) separator
+    /// 4. Returns a [`Curie`] with the prefix and reference components
     ///
     /// # Arguments
     ///
-    /// * `curie` - A string slice containing the CURIE to parse (e.g., "prefix:reference")
+    /// * `input` - A string slice containing the CURIE to parse (e.g., "prefix:reference")
     ///
     /// # Returns
     ///
     /// * `Ok(Curie)` - Successfully parsed CURIE
-    /// * `Err(CurieParsingError::InvalidCurie)` - The input failed validation
+    /// * `Err(CurieParsingError::InvalidCurie)` - The input failed validation, with the reason
+    ///   given by [`CurieValidation::explain`]
     /// * `Err(CurieParsingError::UnparsableCurie)` - The input passed validation but
     ///   couldn't be split into prefix and reference (no colon found)
     ///
@@ -60,17 +70,32 @@ impl<Validator: CurieValidation> CurieParsing for CurieParser<Validator> {
     /// let result = parser.parse("invalid");
     /// assert!(result.is_err());
     /// ```
-    fn parse(&self, curie: &str) -> Result<Curie, CurieParsingError> {
-        match self.validator.validate(curie) {
-            true => {
-                if let Some((prefix, reference)) = curie.split_once(':') {
-                    Ok(Curie::new(prefix, reference))
-                } else {
-                    Err(CurieParsingError::UnparsableCurie(curie.to_string()))
-                }
-            }
-            false => Err(CurieParsingError::InvalidCurie(curie.to_string())),
+    fn parse(&self, input: &str) -> Result<Curie, CurieParsingError> {
+        self.parse_ref(input).map(CurieRef::to_owned)
+    }
+
+    /// Parses a CURIE like [`parse`](Self::parse), borrowing the prefix and reference from
+    /// the input. A normalized prefix is borrowed from the Bioregistry metadata.
+    fn parse_ref<'a>(&self, input: &'a str) -> Result<CurieRef<'a>, CurieParsingError> {
+        let curie = self.prefix_normalization.normalize(input);
+
+        if let Some(reason) = self.validator.explain(&curie) {
+            return Err(CurieParsingError::InvalidCurie {
+                curie: input.to_string(),
+                reason,
+            });
         }
+        let Some((prefix, reference)) = input.split_once(':') else {
+            return Err(CurieParsingError::UnparsableCurie(input.to_string()));
+        };
+        let prefix = match curie {
+            Cow::Borrowed(_) => prefix,
+            Cow::Owned(_) => self
+                .prefix_normalization
+                .normalize_prefix(prefix)
+                .expect("normalized prefixes are preferred prefixes"),
+        };
+        Ok(CurieRef::new(prefix, reference))
     }
 }
 
@@ -78,13 +103,71 @@ impl<Validator: CurieValidation> CurieParser<Validator> {
     pub fn new(validators: Validator) -> CurieParser<Validator> {
         CurieParser {
             validator: validators,
+            prefix_normalization: PrefixNormalization::Exact,
         }
     }
+
+    /// Sets how the prefix of a CURIE is normalized before it is validated.
+    ///
+    /// See [`PrefixNormalization`].
+    pub fn with_prefix_normalization(mut self, prefix_normalization: PrefixNormalization) -> Self {
+        self.prefix_normalization = prefix_normalization;
+        self
+    }
+
+    pub fn prefix_normalization(&self) -> PrefixNormalization {
+        self.prefix_normalization
+    }
+}
+
+impl CurieParser<CurieRegexValidator> {
+    /// Returns the metadata of the ontology this parser validates against.
+    ///
+    /// See [`CurieRegexValidator::metadata`].
+    pub fn metadata(&self) -> Option<&'static OntologyMetadata> {
+        self.validator.metadata()
+    }
+
+    /// Returns a parser for the ontology with the given prefix, ignoring case.
+    ///
+    /// Like [`CurieParser::from_prefix`], but the error suggests the closest supported
+    /// prefixes, see [`UnknownPrefixError::suggestions`].
+    ///
+    /// ```
+    /// use securiety::{CurieParser, CurieParsing};
+    ///
+    /// let parser = CurieParser::try_from_prefix("mondo").unwrap();
+    /// assert!(parser.parse("MONDO:0006007").is_ok());
+    ///
+    /// let error = CurieParser::try_from_prefix("MONDO_").unwrap_err();
+    /// assert_eq!(error.suggestions(), ["MONDO"]);
+    /// ```
+    pub fn try_from_prefix(prefix: &str) -> Result<Self, UnknownPrefixError> {
+        Self::from_prefix(prefix).ok_or_else(|| UnknownPrefixError::new(prefix))
+    }
+
+    /// Returns a parser that only accepts CURIEs of the ontologies with the given prefixes.
+    ///
+    /// See [`AllowListParser::from_prefixes`].
+    ///
+    /// ```
+    /// use securiety::{CurieParser, CurieParsing};
+    ///
+    /// let parser = CurieParser::any_of(&["MONDO", "OMIM", "ORDO"]).unwrap();
+    /// assert!(parser.parse("ORDO:558").is_ok());
+    /// assert!(parser.parse("HP:0000118").is_err());
+    ///
+    /// assert!(CurieParser::any_of(&["MONDO", "custom"]).is_err());
+    /// ```
+    pub fn any_of(prefixes: &[&str]) -> Result<AllowListParser, UnknownPrefixError> {
+        AllowListParser::from_prefixes(prefixes)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::InvalidCurieReason;
     use mockall::predicate::*;
     use mockall::*;
 
@@ -131,7 +214,10 @@ mod tests {
         let result = parser.parse(curie_str);
 
         match result {
-            Err(CurieParsingError::InvalidCurie(s)) => assert_eq!(s, curie_str),
+            Err(CurieParsingError::InvalidCurie { curie, reason }) => {
+                assert_eq!(curie, curie_str);
+                assert_eq!(reason, InvalidCurieReason::Rejected);
+            }
             _ => panic!("Expected InvalidCurie error, got {:?}", result),
         }
     }
@@ -155,4 +241,14 @@ mod tests {
             _ => panic!("Expected UnparsableCurie error, got {:?}", result),
         }
     }
+
+    #[test]
+    fn test_parse_ref_uses_preferred_prefix() {
+        let parser = CurieParser::fbbt().with_prefix_normalization(PrefixNormalization::Case);
+
+        let curie = parser.parse_ref("FBBT:00000001").unwrap();
+        assert_eq!(curie.prefix(), "FBbt");
+        assert_eq!(curie.reference(), "00000001");
+        assert!(parser.parse_ref("FBbt:1").is_err());
+    }
 }
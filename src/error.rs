@@ -1,23 +1,118 @@
+use crate::suggestions::suggest_prefixes;
+
+#[non_exhaustive]
 #[derive(Debug)]
 pub enum CurieParsingError {
-    InvalidCurie(String),
+    InvalidCurie {
+        curie: String,
+        reason: InvalidCurieReason,
+    },
     UnparsableCurie(String),
+    UnknownPrefix(UnknownPrefixError),
+    PrefixNotAllowed {
+        curie: String,
+        allowed: Vec<&'static str>,
+    },
 }
 
 impl std::fmt::Display for CurieParsingError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CurieParsingError::InvalidCurie(curie_string) => {
-                write!(f, "Invalid CURIE: {}", curie_string)
+            CurieParsingError::InvalidCurie { curie, reason } => {
+                write!(f, "Invalid CURIE: {} ({})", curie, reason)
             }
             CurieParsingError::UnparsableCurie(curie_string) => {
                 write!(f, "Unparsable CURIE: {}", curie_string)
             }
+            CurieParsingError::UnknownPrefix(error) => write!(f, "{}", error),
+            CurieParsingError::PrefixNotAllowed { curie, allowed } => {
+                write!(
+                    f,
+                    "CURIE prefix not allowed: {} (allowed: {})",
+                    curie,
+                    allowed.join(", ")
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for CurieParsingError {}
+
+impl From<UnknownPrefixError> for CurieParsingError {
+    fn from(error: UnknownPrefixError) -> Self {
+        CurieParsingError::UnknownPrefix(error)
+    }
+}
+
+/// The reason why a CURIE was rejected, see [`CurieValidation::explain`](crate::CurieValidation::explain).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidCurieReason {
+    /// There is no colon (`:`) between prefix and reference.
+    MissingSeparator,
+    EmptyPrefix,
+    EmptyReference,
+    /// A character that is not allowed at its position, e.g. whitespace. The offset is in bytes.
+    IllegalCharacter {
+        offset: usize,
+        character: char,
+    },
+    /// The prefix differs from the prefix of the ontology, e.g. `hp` instead of `HP`.
+    PrefixMismatch {
+        expected: &'static str,
+        found: String,
+    },
+    /// The reference does not match the pattern of the ontology.
+    ReferenceMismatch {
+        pattern: &'static str,
+        example: Option<&'static str>,
+    },
+    /// The reference repeats the prefix, e.g. `GO:GO:0008150`. Such CURIEs are accepted by
+    /// [`parse_lenient`](crate::CurieParsing::parse_lenient).
+    Banana {
+        banana: &'static str,
+    },
+    /// The CURIE does not match the regex of a custom [`CurieRegexValidator`](crate::CurieRegexValidator).
+    PatternMismatch {
+        pattern: String,
+    },
+    /// The validator rejected the CURIE without giving a reason.
+    Rejected,
+}
+
+impl std::fmt::Display for InvalidCurieReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidCurieReason::MissingSeparator => {
+                write!(f, "missing ':' between prefix and reference")
+            }
+            InvalidCurieReason::EmptyPrefix => write!(f, "empty prefix"),
+            InvalidCurieReason::EmptyReference => write!(f, "empty reference"),
+            InvalidCurieReason::IllegalCharacter { offset, character } => {
+                write!(f, "illegal character {:?} at byte {}", character, offset)
+            }
+            InvalidCurieReason::PrefixMismatch { expected, found } => {
+                write!(f, "expected prefix `{}`, got `{}`", expected, found)
+            }
+            InvalidCurieReason::ReferenceMismatch { pattern, example } => {
+                write!(f, "reference does not match `{}`", pattern)?;
+                match example {
+                    Some(example) => write!(f, ", e.g. `{}`", example),
+                    None => Ok(()),
+                }
+            }
+            InvalidCurieReason::Banana { banana } => {
+                write!(f, "reference starts with redundant `{}`", banana)
+            }
+            InvalidCurieReason::PatternMismatch { pattern } => {
+                write!(f, "does not match `{}`", pattern)
+            }
+            InvalidCurieReason::Rejected => write!(f, "rejected by validator"),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct InvalidRegexError(pub(crate) regex::Error);
 
@@ -28,3 +123,54 @@ impl std::fmt::Display for InvalidRegexError {
 }
 
 impl std::error::Error for InvalidRegexError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownPrefixError {
+    prefix: String,
+    suggestions: Vec<&'static str>,
+}
+
+impl UnknownPrefixError {
+    pub(crate) fn new(prefix: &str) -> Self {
+        UnknownPrefixError {
+            prefix: prefix.to_string(),
+            suggestions: suggest_prefixes(prefix),
+        }
+    }
+
+    /// The prefix that could not be resolved.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// The supported prefixes closest to [`prefix`](Self::prefix), best match first.
+    ///
+    /// Prefixes are suggested if the unknown prefix is one of their synonyms (`HPO` for
+    /// `HP`), or differs from them or their synonyms by a few characters (`MONDO_` for
+    /// `MONDO`).
+    ///
+    /// ```
+    /// use securiety::CurieParser;
+    ///
+    /// let error = CurieParser::try_from_prefix("HPO").unwrap_err();
+    /// assert_eq!(error.suggestions()[0], "HP");
+    ///
+    /// let error = CurieParser::try_from_prefix("Orphanet").unwrap_err();
+    /// assert_eq!(error.to_string(), "Unknown CURIE prefix: Orphanet (did you mean ORDO?)");
+    /// ```
+    pub fn suggestions(&self) -> &[&'static str] {
+        &self.suggestions
+    }
+}
+
+impl std::fmt::Display for UnknownPrefixError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown CURIE prefix: {}", self.prefix)?;
+        match self.suggestions.as_slice() {
+            [] => Ok(()),
+            suggestions => write!(f, " (did you mean {}?)", suggestions.join(", ")),
+        }
+    }
+}
+
+impl std::error::Error for UnknownPrefixError {}
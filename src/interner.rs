@@ -0,0 +1,144 @@
+use crate::curie::Curie;
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// A cheap handle to a CURIE interned by a [`CurieInterner`].
+///
+/// Ids are only meaningful for the interner that returned them. They are ordered by the time
+/// their CURIE was first interned, not like the CURIEs themselves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CurieId(u32);
+
+impl CurieId {
+    /// The position of the CURIE among all CURIEs interned by its interner, starting at 0.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Stores each distinct [`Curie`] once and hands out [`CurieId`]s for them.
+///
+/// Ids are `Copy` and 4 bytes large, so they can be used as keys of graphs or maps instead
+/// of cloning CURIEs. The interner can be shared between threads, e.g. in an `Arc`:
+/// interning a known CURIE and resolving ids only take a read lock.
+///
+/// # Examples
+///
+/// ```
+/// use securiety::{CurieInterner, CurieParser, CurieParsing};
+/// let parser = CurieParser::hp();
+/// let interner = CurieInterner::new();
+///
+/// let abnormality = interner.intern(&parser.parse("HP:0000118").unwrap());
+/// let again = interner.intern(&parser.parse("HP:0000118").unwrap());
+/// assert_eq!(abnormality, again);
+/// assert_eq!(interner.len(), 1);
+///
+/// assert_eq!(interner.resolve(abnormality).unwrap().to_string(), "HP:0000118");
+/// ```
+#[derive(Debug, Default)]
+pub struct CurieInterner {
+    inner: RwLock<Interned>,
+}
+
+#[derive(Debug, Default)]
+struct Interned {
+    curies: Vec<Arc<Curie>>,
+    ids: HashMap<Arc<Curie>, CurieId>,
+}
+
+impl CurieInterner {
+    pub fn new() -> Self {
+        CurieInterner::default()
+    }
+
+    /// Returns the id of a CURIE, interning a copy of it if it was not interned before.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than `u32::MAX` distinct CURIEs are interned.
+    pub fn intern(&self, curie: &Curie) -> CurieId {
+        if let Some(id) = self.get(curie) {
+            return id;
+        }
+
+        let mut inner = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        if let Some(&id) = inner.ids.get(curie) {
+            return id;
+        }
+        let id = CurieId(u32::try_from(inner.curies.len()).expect("too many interned CURIEs"));
+        let curie = Arc::new(curie.clone());
+        inner.curies.push(Arc::clone(&curie));
+        inner.ids.insert(curie, id);
+        id
+    }
+
+    /// Returns the id of a CURIE, or `None` if it was not interned.
+    pub fn get(&self, curie: &Curie) -> Option<CurieId> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner.ids.get(curie).copied()
+    }
+
+    /// Returns the CURIE of an id, or `None` if the id was returned by another interner.
+    pub fn resolve(&self, id: CurieId) -> Option<Arc<Curie>> {
+        let inner = self.inner.read().unwrap_or_else(PoisonError::into_inner);
+        inner.curies.get(id.index()).cloned()
+    }
+
+    /// The number of distinct CURIEs interned.
+    pub fn len(&self) -> usize {
+        self.inner
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .curies
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interns_distinct_curies() {
+        let interner = CurieInterner::new();
+        let hp = interner.intern(&Curie::new("HP", "0000118"));
+        let mondo = interner.intern(&Curie::new("MONDO", "0006007"));
+
+        assert_ne!(hp, mondo);
+        assert_eq!((hp.index(), mondo.index()), (0, 1));
+        assert_eq!(interner.intern(&Curie::new("HP", "0000118")), hp);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn test_get_and_resolve() {
+        let interner = CurieInterner::new();
+        let curie = Curie::new("HP", "0000118");
+
+        assert_eq!(interner.get(&curie), None);
+        let id = interner.intern(&curie);
+        assert_eq!(interner.get(&curie), Some(id));
+        assert_eq!(interner.resolve(id).as_deref(), Some(&curie));
+        assert_eq!(CurieInterner::new().resolve(id), None);
+    }
+
+    #[test]
+    fn test_interns_across_threads() {
+        let interner = CurieInterner::new();
+
+        let ids: Vec<_> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| interner.intern(&Curie::new("HP", "0000118"))))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        assert!(ids.iter().all(|&id| id == ids[0]));
+        assert_eq!(interner.len(), 1);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::curie::Curie;
+use crate::ontology::Ontology;
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+
+const OBO_PURLS: [&str; 2] = [
+    "http://purl.obolibrary.org/obo/",
+    "https://purl.obolibrary.org/obo/",
+];
+
+/// A rewrite applied by [`CurieParsing::parse_lenient`](crate::CurieParsing::parse_lenient)
+/// to turn a common CURIE spelling into a CURIE.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rewrite {
+    /// Leading and trailing whitespace was removed, e.g. ` HP:0000118 `.
+    Trimmed,
+    /// Enclosing angle brackets were removed, e.g. `<HP:0000118>`.
+    AngleBrackets,
+    /// The OBO PURL namespace was removed, e.g. `http://purl.obolibrary.org/obo/HP_0000118`.
+    OboPurl,
+    /// The `obo:` prefix was removed, e.g. `obo:HP_0000118`.
+    OboPrefix,
+    /// The underscore between prefix and reference was replaced by a colon, e.g. `HP_0000118`.
+    Underscore,
+    /// The prefix repeated at the start of the reference was removed, e.g. `GO:GO:0008150`.
+    /// See [`OntologyMetadata::banana`](crate::OntologyMetadata::banana).
+    Banana,
+    /// Leading zeros were added to a numeric reference, e.g. `HP:118`. Only applied by
+    /// [`CurieParsing::parse_zero_padded`](crate::CurieParsing::parse_zero_padded).
+    ZeroPadded,
+}
+
+impl Display for Rewrite {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Rewrite::Trimmed => "removed surrounding whitespace",
+            Rewrite::AngleBrackets => "removed angle brackets",
+            Rewrite::OboPurl => "removed OBO PURL namespace",
+            Rewrite::OboPrefix => "removed `obo:` prefix",
+            Rewrite::Underscore => "replaced `_` separator with `:`",
+            Rewrite::Banana => "removed prefix repeated in reference",
+            Rewrite::ZeroPadded => "added leading zeros to reference",
+        };
+        f.write_str(description)
+    }
+}
+
+/// A CURIE parsed by [`CurieParsing::parse_lenient`](crate::CurieParsing::parse_lenient) or
+/// [`CurieParsing::parse_zero_padded`](crate::CurieParsing::parse_zero_padded), together with
+/// the rewrites that were applied to the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LenientCurie {
+    curie: Curie,
+    rewrites: Vec<Rewrite>,
+}
+
+impl LenientCurie {
+    pub(crate) fn new(curie: Curie, rewrites: Vec<Rewrite>) -> Self {
+        LenientCurie { curie, rewrites }
+    }
+
+    pub fn curie(&self) -> &Curie {
+        &self.curie
+    }
+
+    /// The rewrites applied to the input, in order. Empty if the input was a CURIE.
+    pub fn rewrites(&self) -> &[Rewrite] {
+        &self.rewrites
+    }
+
+    /// Whether the input was rewritten, i.e. differs from the parsed CURIE.
+    pub fn is_modified(&self) -> bool {
+        !self.rewrites.is_empty()
+    }
+
+    pub fn into_curie(self) -> Curie {
+        self.curie
+    }
+}
+
+/// Rewrites common CURIE spellings into a CURIE, returning the applied rewrites.
+pub(crate) fn rewrite(input: &str) -> (Cow<'_, str>, Vec<Rewrite>) {
+    let mut rewrites = Vec::new();
+    let mut curie = input;
+
+    let trimmed = curie.trim();
+    if trimmed.len() != curie.len() {
+        rewrites.push(Rewrite::Trimmed);
+        curie = trimmed;
+    }
+
+    if let Some(inner) = curie.strip_prefix('<').and_then(|c| c.strip_suffix('>')) {
+        rewrites.push(Rewrite::AngleBrackets);
+        curie = inner;
+    }
+
+    if let Some(local_id) = OBO_PURLS.iter().find_map(|purl| curie.strip_prefix(purl)) {
+        rewrites.push(Rewrite::OboPurl);
+        curie = local_id;
+    } else if let Some(local_id) = curie.strip_prefix("obo:") {
+        rewrites.push(Rewrite::OboPrefix);
+        curie = local_id;
+    }
+
+    let (prefix, reference) = match curie.split_once(':') {
+        Some(parts) => parts,
+        None => match underscore_separator(curie) {
+            Some(separator) => {
+                rewrites.push(Rewrite::Underscore);
+                (&curie[..separator], &curie[separator + 1..])
+            }
+            None => return (Cow::Borrowed(curie), rewrites),
+        },
+    };
+
+    let unpeeled = Ontology::from_prefix(prefix)
+        .and_then(|ontology| ontology.metadata().strip_banana(reference));
+    let reference = match unpeeled {
+        Some(unpeeled) => {
+            rewrites.push(Rewrite::Banana);
+            unpeeled
+        }
+        None => reference,
+    };
+
+    match rewrites.last() {
+        Some(Rewrite::Underscore | Rewrite::Banana) => {
+            (Cow::Owned(format!("{prefix}:{reference}")), rewrites)
+        }
+        _ => (Cow::Borrowed(curie), rewrites),
+    }
+}
+
+/// Pads the reference of a CURIE with leading zeros to the width its ontology expects.
+pub(crate) fn zero_pad(curie: &str) -> Option<String> {
+    let (prefix, reference) = curie.split_once(':')?;
+    let padded = Ontology::from_prefix(prefix)?
+        .metadata()
+        .pad_reference(reference)?;
+    Some(format!("{prefix}:{padded}"))
+}
+
+/// Finds the underscore that separates prefix and reference in an OBO local ID.
+///
+/// Prefixes can contain underscores themselves (`APOLLO_SV_00000001`), so the longest
+/// supported prefix is preferred. Otherwise, the last underscore is the separator.
+fn underscore_separator(local_id: &str) -> Option<usize> {
+    let mut separators = local_id.match_indices('_').map(|(index, _)| index);
+    separators
+        .clone()
+        .rfind(|&index| Ontology::from_prefix(&local_id[..index]).is_some())
+        .or_else(|| separators.next_back())
+        .filter(|&index| index > 0 && index + 1 < local_id.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_curies() {
+        let (curie, rewrites) = rewrite("HP:0000118");
+        assert!(matches!(curie, Cow::Borrowed("HP:0000118")));
+        assert!(rewrites.is_empty());
+    }
+
+    #[test]
+    fn test_rewrites_obo_spellings() {
+        assert_eq!(
+            rewrite("HP_0000118"),
+            (Cow::Borrowed("HP:0000118"), vec![Rewrite::Underscore])
+        );
+        assert_eq!(
+            rewrite("obo:HP_0000118"),
+            (
+                Cow::Borrowed("HP:0000118"),
+                vec![Rewrite::OboPrefix, Rewrite::Underscore]
+            )
+        );
+        assert_eq!(
+            rewrite("https://purl.obolibrary.org/obo/HP_0000118"),
+            (
+                Cow::Borrowed("HP:0000118"),
+                vec![Rewrite::OboPurl, Rewrite::Underscore]
+            )
+        );
+    }
+
+    #[test]
+    fn test_rewrites_wrapped_curies() {
+        assert_eq!(
+            rewrite(" <HP:0000118>\n"),
+            (
+                Cow::Borrowed("HP:0000118"),
+                vec![Rewrite::Trimmed, Rewrite::AngleBrackets]
+            )
+        );
+        assert_eq!(
+            rewrite("<http://purl.obolibrary.org/obo/HP_0000118>").1,
+            [
+                Rewrite::AngleBrackets,
+                Rewrite::OboPurl,
+                Rewrite::Underscore
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strips_banana() {
+        assert_eq!(
+            rewrite("GO:GO:0008150"),
+            (Cow::Borrowed("GO:0008150"), vec![Rewrite::Banana])
+        );
+        assert_eq!(
+            rewrite("<CHEBI:CHEBI:1234>"),
+            (
+                Cow::Borrowed("CHEBI:1234"),
+                vec![Rewrite::AngleBrackets, Rewrite::Banana]
+            )
+        );
+        assert_eq!(rewrite("MONDO:MONDO:0000001").1, []);
+        assert_eq!(rewrite("GO:0008150").1, []);
+    }
+
+    #[test]
+    fn test_zero_pad() {
+        assert_eq!(zero_pad("HP:118").as_deref(), Some("HP:0000118"));
+        assert_eq!(zero_pad("hp:118").as_deref(), Some("hp:0000118"));
+        assert_eq!(zero_pad("HP:0000118"), None);
+        assert_eq!(zero_pad("OMIM:118"), None);
+        assert_eq!(zero_pad("custom:118"), None);
+    }
+
+    #[test]
+    fn test_underscore_separator() {
+        assert_eq!(underscore_separator("APOLLO_SV_00000001"), Some(9));
+        assert_eq!(underscore_separator("custom_prefix_1"), Some(13));
+        assert_eq!(underscore_separator("HP0000118"), None);
+        assert_eq!(underscore_separator("_0000118"), None);
+        assert_eq!(underscore_separator("HP_"), None);
+    }
+}
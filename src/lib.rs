@@ -34,27 +34,176 @@
 //! # }
 //! ```
 //!
+//! ## Expansion
+//!
+//! A [`Curie`] can be expanded into its full IRI with a [`PrefixMap`]:
+//!
+//! ```
+//! use securiety::{CurieParser, CurieParsing, CurieParsingError, PrefixMap};
+//! # fn main() -> Result<(), CurieParsingError> {
+//! let curie = CurieParser::hp().parse("HP:0000738")?;
+//! let iri = curie.expand(&PrefixMap::bioregistry());
+//!
+//! assert_eq!(iri.as_deref(), Some("http://purl.obolibrary.org/obo/HP_0000738"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The prefixes that parsers can be instantiated for are listed by [`supported_prefixes`].
+//! When the choice of ontology is stored, e.g. in a configuration file, the [`Ontology`] enum
+//! offers the same lookup with `FromStr` and `Display`:
+//!
+//! ```
+//! use securiety::{CurieParsing, Ontology};
+//! let ontology: Ontology = "HP".parse().unwrap();
+//!
+//! assert!(ontology.parser().parse("HP:0000738").is_ok());
+//! ```
+//!
+//! ## Mixed ontologies
+//!
+//! When CURIEs of several ontologies are mixed, a [`RegistryParser`] validates each CURIE
+//! against the ontology its prefix refers to:
+//!
+//! ```
+//! use securiety::{CurieParsing, RegistryParser};
+//! let parser = RegistryParser::default();
+//!
+//! for curie in ["HP:0000738", "MONDO:0006007", "OMIM:603903"] {
+//!     assert!(parser.parse(curie).is_ok());
+//! }
+//! ```
+//!
+//! To only accept CURIEs of some ontologies, e.g. disease identifiers, restrict the parser to
+//! their prefixes with [`CurieParser::any_of`]:
+//!
+//! ```
+//! use securiety::{CurieParser, CurieParsing};
+//! let parser = CurieParser::any_of(&["MONDO", "OMIM", "ORDO"]).unwrap();
+//!
+//! assert!(parser.parse("OMIM:603903").is_ok());
+//! assert!(parser.parse("HP:0000738").is_err());
+//! ```
+//!
+//! ## Custom rules
+//!
+//! Validators can be combined with [`AnyOf`], [`AllOf`], [`Not`], [`PrefixIs`] and
+//! [`FnValidator`] to express project-specific rules:
+//!
+//! ```
+//! use securiety::{AllOf, CurieParser, CurieParsing, CurieRegexValidator, FnValidator, Not};
+//! let blocked = ["HP:0000001"];
+//! let parser = CurieParser::new(AllOf((
+//!     CurieRegexValidator::hp(),
+//!     Not(FnValidator(|curie: &str| blocked.contains(&curie))),
+//! )));
+//!
+//! assert!(parser.parse("HP:0000738").is_ok());
+//! assert!(parser.parse("HP:0000001").is_err());
+//! ```
+//!
 //! # Features
 //!
 //! - Parse CURIE strings into structured [`Curie`] objects
 //! - Validate CURIEs against various formats
 //! - Support for specific ontology parsers (HP, MONDO, etc.)
+//! - Bioregistry metadata (name, homepage, example, ...) for every supported ontology
+//! - Expand CURIEs into IRIs and compress IRIs into CURIEs using Bioregistry URI formats
+//!
+//! # Cargo features
+//!
+//! - `macros` - The `curie!` macro, which checks CURIE literals against the
+//!   pattern of their ontology at compile time.
+//! - `serde` - `Serialize` and `Deserialize` for [`Curie`], as its string form. Deserialization
+//!   validates the CURIE like [`CurieParser::general`]. The `serde` module provides
+//!   `#[serde(with = "...")]` helpers that only accept CURIEs of one ontology.
 //!
 //! # Modules
 //!
-//! - [`curie`] - Core CURIE data structure
-//! - [`curie_parser`] - Parsing and expansion logic
-//! - [`validators`] - CURIE validation implementations
+//! - [`allow_list_parser`] - Parsing CURIEs of a fixed set of ontologies
+//! - [`classifier`] - Finding the ontologies whose pattern matches a CURIE or a bare reference
+//! - [`curie`](mod@curie) - Core CURIE data structure
+//! - [`curie_parser`] - Parsing logic
+//! - [`interner`] - Interning CURIEs as cheap, copyable ids
+//! - [`lenient`] - Lenient parsing of common CURIE spellings
+//! - [`metadata`] - Bioregistry metadata of the supported ontologies
+//! - [`natural`] - Natural ordering of CURIEs with numeric references
+//! - [`normalization`] - Normalization of prefix casing and synonyms
+//! - [`ontology`] - The supported ontologies as an enum
+//! - [`packed`] - A compact representation of CURIEs for large in-memory sets
+//! - [`prefix_map`] - Prefix to URI mappings, CURIE expansion and IRI compression
+//! - [`registry_parser`] - Parsing CURIEs of mixed ontologies
+//! - [`validators`] - CURIE validation implementations and [`combinators`](validators::combinators)
+//! - `serde` - Serde helpers for CURIEs of one ontology (requires the `serde` feature)
 //! - [`traits`] - Common traits for extensibility
 //! - [`error`] - Error types
 
+pub mod allow_list_parser;
+pub use allow_list_parser::AllowListParser;
+pub mod classifier;
+pub use classifier::CurieClassifier;
 pub mod curie;
-pub use curie::Curie;
+pub use curie::{Curie, CurieRef};
+/// Creates a [`Curie`] from a string literal that is validated at compile time.
+///
+/// The prefix must be the preferred prefix of a [supported](supported_prefixes) ontology, and
+/// the CURIE must match that ontology's pattern. Anything else is a compile error with the
+/// message a [`RegistryParser`] would return at runtime, minus the suggestions for unknown
+/// prefixes, so typos in hard-coded CURIEs fail the build instead of an `unwrap()`. Requires
+/// the `macros` feature.
+///
+/// ```
+/// use securiety::{CurieParser, CurieParsing, curie};
+///
+/// let phenotypic_abnormality = curie!("HP:0000118");
+/// assert_eq!(phenotypic_abnormality, CurieParser::hp().parse("HP:0000118").unwrap());
+/// ```
+///
+/// A reference that does not match the pattern of its ontology does not compile:
+///
+/// ```compile_fail
+/// let curie = securiety::curie!("HP:000118");
+/// ```
+///
+/// Neither does a prefix that is not supported, or not spelled like the preferred prefix:
+///
+/// ```compile_fail
+/// let curie = securiety::curie!("HPO:0000118");
+/// ```
+///
+/// ```compile_fail
+/// let curie = securiety::curie!("hp:0000118");
+/// ```
+#[cfg(feature = "macros")]
+pub use securiety_macros::curie;
 pub mod curie_parser;
 pub use curie_parser::CurieParser;
 pub mod error;
 pub use error::*;
+pub mod interner;
+pub use interner::{CurieId, CurieInterner};
+pub mod lenient;
+pub use lenient::{LenientCurie, Rewrite};
+pub mod metadata;
+pub use metadata::OntologyMetadata;
+pub mod natural;
+pub use natural::NaturalOrd;
+pub mod normalization;
+pub use normalization::PrefixNormalization;
+pub mod ontology;
+pub use ontology::Ontology;
+pub mod packed;
+pub use packed::{DisplayPacked, PackedCurie, PackedCurieArena};
+pub mod prefix_map;
+pub use prefix_map::PrefixMap;
+pub mod registry_parser;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub use registry_parser::{RegistryParser, UnknownPrefixPolicy};
+mod suggestions;
 pub mod traits;
 pub use traits::*;
 pub mod validators;
+pub use validators::combinators::{AllOf, AnyOf, FnValidator, Not, PrefixIs};
+pub use validators::curie_patterns::{BIOREGISTRY_RELEASE, supported_prefixes};
 pub use validators::regex_validator::CurieRegexValidator;
@@ -0,0 +1,228 @@
+use crate::ontology::Ontology;
+use crate::validators::curie_patterns::ONTOLOGIES;
+
+/// Bioregistry metadata of an ontology with a generated validator.
+///
+/// Metadata is available for every ontology in
+/// [`curie_patterns`](crate::validators::curie_patterns), either through the validator or
+/// parser of the ontology, or by looking up its prefix.
+///
+/// # Examples
+///
+/// ```
+/// use securiety::{CurieParser, OntologyMetadata};
+///
+/// let metadata = OntologyMetadata::from_prefix("hp").unwrap();
+/// assert_eq!(metadata.prefix(), "HP");
+/// assert_eq!(metadata.name(), "Human Phenotype Ontology");
+/// assert_eq!(metadata.example(), Some("0000118"));
+///
+/// assert_eq!(CurieParser::hp().metadata(), Some(metadata));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct OntologyMetadata {
+    pub(crate) prefix: &'static str,
+    pub(crate) name: &'static str,
+    pub(crate) synonyms: &'static [&'static str],
+    pub(crate) description: Option<&'static str>,
+    pub(crate) homepage: Option<&'static str>,
+    pub(crate) example: Option<&'static str>,
+    pub(crate) banana: Option<&'static str>,
+    pub(crate) uri_format: Option<&'static str>,
+    pub(crate) license: Option<&'static str>,
+    pub(crate) pattern: &'static str,
+}
+
+impl OntologyMetadata {
+    /// Returns the metadata of all ontologies with a generated validator, in the same
+    /// order as [`supported_prefixes`](crate::supported_prefixes).
+    pub fn all() -> &'static [&'static OntologyMetadata] {
+        ONTOLOGIES
+    }
+
+    /// Looks up the metadata of an ontology by its prefix, ignoring case.
+    pub fn from_prefix(prefix: &str) -> Option<&'static Self> {
+        Ontology::from_prefix(prefix).map(Ontology::metadata)
+    }
+
+    /// The preferred prefix of the ontology, with its canonical casing (e.g. `FBbt`).
+    pub fn prefix(&self) -> &'static str {
+        self.prefix
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Other prefixes that are used for the ontology, e.g. `MIM` for `OMIM`.
+    ///
+    /// Case variants of [`prefix`](Self::prefix) are not included.
+    pub fn synonyms(&self) -> &'static [&'static str] {
+        self.synonyms
+    }
+
+    pub fn description(&self) -> Option<&'static str> {
+        self.description
+    }
+
+    pub fn homepage(&self) -> Option<&'static str> {
+        self.homepage
+    }
+
+    /// An example reference, i.e. the part of a CURIE after the colon.
+    pub fn example(&self) -> Option<&'static str> {
+        self.example
+    }
+
+    /// The text that some data sources repeat at the start of references, e.g. `GO:` in
+    /// `GO:GO:0008150`. The Bioregistry calls this a "banana".
+    ///
+    /// The generated patterns do not accept references with a banana, see
+    /// [`strip_banana`](Self::strip_banana).
+    pub fn banana(&self) -> Option<&'static str> {
+        self.banana
+    }
+
+    /// Removes the [`banana`](Self::banana) from the start of a reference.
+    ///
+    /// Returns `None` if the ontology has no banana, or the reference does not start with it.
+    ///
+    /// ```
+    /// use securiety::OntologyMetadata;
+    /// let go = OntologyMetadata::from_prefix("GO").unwrap();
+    ///
+    /// assert_eq!(go.strip_banana("GO:0008150"), Some("0008150"));
+    /// assert_eq!(go.strip_banana("0008150"), None);
+    /// ```
+    pub fn strip_banana<'a>(&self, reference: &'a str) -> Option<&'a str> {
+        reference.strip_prefix(self.banana?)
+    }
+
+    /// The number of digits of a reference, if the pattern only accepts references of
+    /// exactly that many digits, e.g. 7 for `^HP:\d{7}$` or 8 for `^FOODON:[0-9]{8}$`.
+    pub fn reference_width(&self) -> Option<usize> {
+        let reference = self
+            .pattern
+            .strip_prefix('^')?
+            .strip_prefix(self.prefix)?
+            .strip_prefix(':')?
+            .strip_suffix('$')?;
+        reference
+            .strip_prefix(r"\d{")
+            .or_else(|| reference.strip_prefix("[0-9]{"))?
+            .strip_suffix('}')?
+            .parse()
+            .ok()
+    }
+
+    /// Pads a numeric reference with leading zeros to the [`reference_width`](Self::reference_width).
+    ///
+    /// Returns `None` if the ontology has no fixed reference width, or if the reference is not
+    /// a number shorter than the width.
+    ///
+    /// ```
+    /// use securiety::OntologyMetadata;
+    /// let hp = OntologyMetadata::from_prefix("HP").unwrap();
+    ///
+    /// assert_eq!(hp.pad_reference("118").as_deref(), Some("0000118"));
+    /// assert_eq!(hp.pad_reference("0000118"), None);
+    /// assert_eq!(hp.pad_reference("abc"), None);
+    /// ```
+    pub fn pad_reference(&self, reference: &str) -> Option<String> {
+        let width = self.reference_width()?;
+        let is_number = !reference.is_empty() && reference.bytes().all(|b| b.is_ascii_digit());
+        (is_number && reference.len() < width).then(|| format!("{reference:0>width$}"))
+    }
+
+    /// The URI format of the ontology's terms, where `$1` is replaced by the reference.
+    pub fn uri_format(&self) -> Option<&'static str> {
+        self.uri_format
+    }
+
+    pub fn license(&self) -> Option<&'static str> {
+        self.license
+    }
+
+    /// The regular expression that CURIEs of this ontology are validated against.
+    pub fn pattern(&self) -> &'static str {
+        self.pattern
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+
+    #[test]
+    fn test_from_prefix_ignores_case() {
+        let metadata = OntologyMetadata::from_prefix("fbbt").unwrap();
+        assert_eq!(metadata.prefix(), "FBbt");
+        assert_eq!(OntologyMetadata::from_prefix("FBBT"), Some(metadata));
+    }
+
+    #[test]
+    fn test_from_prefix_unknown() {
+        assert!(OntologyMetadata::from_prefix("general").is_none());
+        assert!(OntologyMetadata::from_prefix("unknown").is_none());
+    }
+
+    #[test]
+    fn test_all_is_indexed_by_ontology() {
+        for (metadata, ontology) in OntologyMetadata::all().iter().zip(Ontology::ALL) {
+            assert_eq!(*metadata, ontology.metadata());
+        }
+        assert_eq!(OntologyMetadata::all().len(), Ontology::ALL.len());
+    }
+
+    #[test]
+    fn test_examples_match_patterns() {
+        for metadata in ONTOLOGIES {
+            if let Some(example) = metadata.example() {
+                let regex = Regex::new(metadata.pattern()).unwrap();
+                let curie = format!("{}:{example}", metadata.prefix());
+                assert!(regex.is_match(&curie), "{curie} does not match its pattern");
+            }
+        }
+    }
+
+    #[test]
+    fn test_examples_have_no_banana() {
+        for metadata in ONTOLOGIES {
+            if let Some(example) = metadata.example() {
+                assert_eq!(metadata.strip_banana(example), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reference_width() {
+        assert_eq!(Ontology::Hp.metadata().reference_width(), Some(7));
+        assert_eq!(Ontology::Fbbt.metadata().reference_width(), Some(8));
+        assert_eq!(Ontology::Foodon.metadata().reference_width(), Some(8));
+        assert_eq!(Ontology::Omim.metadata().reference_width(), None);
+        assert_eq!(Ontology::Ncbitaxon.metadata().reference_width(), None);
+    }
+
+    #[test]
+    fn test_padded_examples_are_unchanged() {
+        for metadata in ONTOLOGIES {
+            if let (Some(example), Some(width)) = (metadata.example(), metadata.reference_width()) {
+                assert_eq!(example.len(), width);
+                assert_eq!(metadata.pad_reference(example), None);
+            }
+        }
+    }
+
+    #[test]
+    fn test_uri_formats_have_placeholder() {
+        for metadata in ONTOLOGIES {
+            if let Some(uri_format) = metadata.uri_format() {
+                assert!(
+                    uri_format.ends_with("$1"),
+                    "{uri_format} has no placeholder"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::curie::Curie;
+use std::cmp::Ordering;
+
+/// A [`Curie`] that orders by [`Curie::cmp_natural`] instead of by its string form.
+///
+/// ```
+/// use securiety::{CurieParser, CurieParsing, NaturalOrd};
+/// let parser = CurieParser::chebi();
+///
+/// let mut curies: Vec<_> = ["CHEBI:10", "CHEBI:9", "CHEBI:100"]
+///     .into_iter()
+///     .map(|curie| NaturalOrd(parser.parse(curie).unwrap()))
+///     .collect();
+/// curies.sort();
+///
+/// let sorted: Vec<_> = curies.iter().map(|curie| curie.0.to_string()).collect();
+/// assert_eq!(sorted, ["CHEBI:9", "CHEBI:10", "CHEBI:100"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NaturalOrd(pub Curie);
+
+impl Ord for NaturalOrd {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_natural(&other.0)
+    }
+}
+
+impl PartialOrd for NaturalOrd {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<Curie> for NaturalOrd {
+    fn from(curie: Curie) -> Self {
+        NaturalOrd(curie)
+    }
+}
+
+/// Compares two strings chunk by chunk, comparing runs of digits by their numeric value.
+///
+/// Strings that only differ in leading zeros, like `007` and `7`, are ordered by their
+/// string form, so that only equal strings compare as equal.
+pub(crate) fn cmp_natural(a: &str, b: &str) -> Ordering {
+    let (mut a_rest, mut b_rest) = (a, b);
+    while !a_rest.is_empty() && !b_rest.is_empty() {
+        let (a_chunk, a_tail) = split_chunk(a_rest);
+        let (b_chunk, b_tail) = split_chunk(b_rest);
+
+        let ordering = match (is_number(a_chunk), is_number(b_chunk)) {
+            (true, true) => cmp_numbers(a_chunk, b_chunk),
+            _ => a_chunk.cmp(b_chunk),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+        (a_rest, b_rest) = (a_tail, b_tail);
+    }
+
+    a_rest.len().cmp(&b_rest.len()).then_with(|| a.cmp(b))
+}
+
+/// Splits off the leading run of digits or of other characters.
+fn split_chunk(s: &str) -> (&str, &str) {
+    let digits = is_number(s);
+    let end = s
+        .find(|c: char| c.is_ascii_digit() != digits)
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+fn is_number(chunk: &str) -> bool {
+    chunk.starts_with(|c: char| c.is_ascii_digit())
+}
+
+/// Compares runs of digits of any length by their value.
+fn cmp_numbers(a: &str, b: &str) -> Ordering {
+    let a = a.trim_start_matches('0');
+    let b = b.trim_start_matches('0');
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compares_numbers_by_value() {
+        assert_eq!(cmp_natural("9", "10"), Ordering::Less);
+        assert_eq!(cmp_natural("100", "99"), Ordering::Greater);
+        assert_eq!(cmp_natural("0000118", "0000054"), Ordering::Greater);
+        assert_eq!(
+            cmp_natural("123456789012345678901234567890", "99"),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_compares_mixed_references() {
+        assert_eq!(cmp_natural("D9", "D10"), Ordering::Less);
+        assert_eq!(cmp_natural("F45.9", "F45.10"), Ordering::Less);
+        assert_eq!(cmp_natural("C34", "D1"), Ordering::Less);
+        assert_eq!(cmp_natural("10", "A"), Ordering::Less);
+        assert_eq!(cmp_natural("D1", "D1a"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_is_consistent_with_eq() {
+        assert_eq!(cmp_natural("7", "7"), Ordering::Equal);
+        assert_eq!(cmp_natural("007", "7"), Ordering::Less);
+        assert_eq!(cmp_natural("7", "007"), Ordering::Greater);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::ontology::Ontology;
+use std::borrow::Cow;
+
+/// Determines how a parser rewrites the prefix of a CURIE before validating it.
+///
+/// The generated patterns are case-sensitive on the prefix, e.g. `^HP:`, so `hp:0000118`
+/// is rejected unless its prefix is normalized first. A normalized prefix is replaced by the
+/// preferred prefix of its ontology, so the parsed [`Curie`](crate::Curie) always has the
+/// canonical casing.
+///
+/// # Examples
+///
+/// ```
+/// use securiety::{CurieParser, CurieParsing, PrefixNormalization};
+///
+/// let parser = CurieParser::omim().with_prefix_normalization(PrefixNormalization::Synonyms);
+/// assert_eq!(parser.parse("omim:603903").unwrap().to_string(), "OMIM:603903");
+/// assert_eq!(parser.parse("MIM:603903").unwrap().to_string(), "OMIM:603903");
+///
+/// assert!(CurieParser::omim().parse("MIM:603903").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PrefixNormalization {
+    /// The prefix is validated as it is.
+    #[default]
+    Exact,
+    /// Case variants of a supported prefix are replaced by the preferred prefix, e.g. `hp`
+    /// or `Hp` by `HP`.
+    Case,
+    /// Like [`Case`](Self::Case), and Bioregistry synonyms are replaced by the preferred
+    /// prefix too, e.g. `MIM` by `OMIM` or `NCBI_Taxon` by `NCBITaxon`.
+    Synonyms,
+}
+
+impl PrefixNormalization {
+    /// Returns the preferred prefix for the given prefix, or `None` if this mode does not
+    /// resolve it to a supported ontology.
+    ///
+    /// ```
+    /// use securiety::PrefixNormalization;
+    ///
+    /// assert_eq!(PrefixNormalization::Case.normalize_prefix("ncbitaxon"), Some("NCBITaxon"));
+    /// assert_eq!(PrefixNormalization::Case.normalize_prefix("MSH"), None);
+    /// assert_eq!(PrefixNormalization::Synonyms.normalize_prefix("MSH"), Some("MESH"));
+    /// ```
+    pub fn normalize_prefix(self, prefix: &str) -> Option<&'static str> {
+        let ontology = match self {
+            PrefixNormalization::Exact => {
+                Ontology::from_prefix(prefix).filter(|ontology| ontology.prefix() == prefix)
+            }
+            PrefixNormalization::Case => Ontology::from_prefix(prefix),
+            PrefixNormalization::Synonyms => Ontology::from_prefix_or_synonym(prefix),
+        };
+        ontology.map(Ontology::prefix)
+    }
+
+    /// Replaces the prefix of a CURIE with its preferred prefix.
+    ///
+    /// The CURIE is borrowed unchanged if it has no colon, if its prefix is not resolved by
+    /// this mode, or if it already has the preferred prefix.
+    pub fn normalize(self, curie: &str) -> Cow<'_, str> {
+        if self == PrefixNormalization::Exact {
+            return Cow::Borrowed(curie);
+        }
+        let Some((prefix, reference)) = curie.split_once(':') else {
+            return Cow::Borrowed(curie);
+        };
+        match self.normalize_prefix(prefix) {
+            Some(preferred) if preferred != prefix => {
+                Cow::Owned(format!("{preferred}:{reference}"))
+            }
+            _ => Cow::Borrowed(curie),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_keeps_curies() {
+        assert_eq!(
+            PrefixNormalization::Exact.normalize("hp:0000118"),
+            "hp:0000118"
+        );
+        assert!(matches!(
+            PrefixNormalization::Exact.normalize("HP:0000118"),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn test_case_fixes_casing() {
+        let normalization = PrefixNormalization::Case;
+
+        assert_eq!(normalization.normalize("hp:0000118"), "HP:0000118");
+        assert_eq!(normalization.normalize("FBBT:00000001"), "FBbt:00000001");
+        assert_eq!(normalization.normalize("MIM:603903"), "MIM:603903");
+        assert_eq!(normalization.normalize("hp0000118"), "hp0000118");
+    }
+
+    #[test]
+    fn test_synonyms_replace_prefix() {
+        let normalization = PrefixNormalization::Synonyms;
+
+        assert_eq!(normalization.normalize("MIM:603903"), "OMIM:603903");
+        assert_eq!(normalization.normalize("msh:D009369"), "MESH:D009369");
+        assert_eq!(normalization.normalize("NCBI_Taxon:9606"), "NCBITaxon:9606");
+        assert_eq!(normalization.normalize("custom:1"), "custom:1");
+    }
+}
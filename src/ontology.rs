@@ -0,0 +1,167 @@
+use crate::curie::Curie;
+use crate::curie_parser::CurieParser;
+use crate::error::{CurieParsingError, UnknownPrefixError};
+use crate::metadata::OntologyMetadata;
+use crate::validators::curie_patterns::{ONTOLOGIES, PREFIXES_LOWERCASE, SYNONYMS_LOWERCASE};
+use crate::validators::regex_validator::CurieRegexValidator;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+pub use crate::validators::curie_patterns::Ontology;
+
+impl Ontology {
+    /// The preferred prefix of the ontology, e.g. `HP` or `NCBITaxon`.
+    pub fn prefix(self) -> &'static str {
+        self.metadata().prefix()
+    }
+
+    pub fn metadata(self) -> &'static OntologyMetadata {
+        ONTOLOGIES[self as usize]
+    }
+
+    /// Looks up an ontology by its prefix, ignoring case.
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        lookup(PREFIXES_LOWERCASE, prefix)
+    }
+
+    /// Looks up an ontology by its prefix or one of its Bioregistry synonyms, ignoring case.
+    ///
+    /// ```
+    /// use securiety::Ontology;
+    ///
+    /// assert_eq!(Ontology::from_prefix_or_synonym("MIM"), Some(Ontology::Omim));
+    /// assert_eq!(Ontology::from_prefix_or_synonym("ncbi_taxon"), Some(Ontology::Ncbitaxon));
+    /// assert_eq!(Ontology::from_prefix_or_synonym("omim"), Some(Ontology::Omim));
+    /// ```
+    pub fn from_prefix_or_synonym(prefix: &str) -> Option<Self> {
+        Ontology::from_prefix(prefix).or_else(|| lookup(SYNONYMS_LOWERCASE, prefix))
+    }
+
+    /// Returns a parser for CURIEs of this ontology.
+    pub fn parser(self) -> CurieParser<CurieRegexValidator> {
+        CurieParser::new(self.validator())
+    }
+
+    /// Creates a CURIE of this ontology from a numeric reference, padded with leading zeros
+    /// to the width the pattern expects, see [`OntologyMetadata::reference_width`].
+    ///
+    /// Fails like [`Curie::from_parts`] if the pattern does not accept the reference, e.g.
+    /// because the ontology has non-numeric references or the number is too large.
+    ///
+    /// ```
+    /// use securiety::Ontology;
+    ///
+    /// assert_eq!(Ontology::Hp.curie_from_number(118).unwrap().to_string(), "HP:0000118");
+    /// assert_eq!(Ontology::Chebi.curie_from_number(15377).unwrap().to_string(), "CHEBI:15377");
+    /// assert!(Ontology::Hp.curie_from_number(12_345_678).is_err());
+    /// assert!(Ontology::Mesh.curie_from_number(9369).is_err());
+    /// ```
+    pub fn curie_from_number(self, number: u64) -> Result<Curie, CurieParsingError> {
+        let reference = match self.metadata().reference_width() {
+            Some(width) => format!("{number:0width$}"),
+            None => number.to_string(),
+        };
+        Curie::from_parts(self.prefix(), &reference)
+    }
+}
+
+impl Display for Ontology {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.prefix())
+    }
+}
+
+impl FromStr for Ontology {
+    type Err = UnknownPrefixError;
+
+    /// Parses an ontology from its prefix, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ontology::from_prefix(s).ok_or_else(|| UnknownPrefixError::new(s))
+    }
+}
+
+/// Finds a prefix in a table generated by `cargo xtask codegen`, ignoring its case.
+fn lookup(table: &[(&str, u16)], prefix: &str) -> Option<Ontology> {
+    let prefix = prefix.bytes().map(|b| b.to_ascii_lowercase());
+    table
+        .binary_search_by(|(entry, _)| entry.bytes().cmp(prefix.clone()))
+        .ok()
+        .map(|index| Ontology::ALL[usize::from(table[index].1)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::supported_prefixes;
+
+    #[test]
+    fn test_all_matches_supported_prefixes() {
+        let prefixes: Vec<_> = Ontology::ALL.iter().map(|o| o.prefix()).collect();
+        assert_eq!(prefixes, supported_prefixes());
+    }
+
+    #[test]
+    fn test_display_roundtrip() {
+        for ontology in Ontology::ALL {
+            assert_eq!(ontology.to_string().parse::<Ontology>().unwrap(), *ontology);
+        }
+    }
+
+    #[test]
+    fn test_from_str_ignores_case() {
+        assert_eq!("fbbt".parse::<Ontology>().unwrap(), Ontology::Fbbt);
+        assert_eq!("FBbt".parse::<Ontology>().unwrap(), Ontology::Fbbt);
+        assert_eq!(Ontology::Fbbt.to_string(), "FBbt");
+    }
+
+    #[test]
+    fn test_from_str_unknown() {
+        let err = "general".parse::<Ontology>().unwrap_err();
+        assert_eq!(err.prefix(), "general");
+    }
+
+    #[test]
+    fn test_from_prefix_finds_every_prefix_and_synonym() {
+        for ontology in Ontology::ALL.iter().copied() {
+            let prefix = ontology.prefix();
+            for variant in [
+                prefix.to_string(),
+                prefix.to_lowercase(),
+                prefix.to_uppercase(),
+            ] {
+                assert_eq!(Ontology::from_prefix(&variant), Some(ontology), "{variant}");
+            }
+            for synonym in ontology.metadata().synonyms() {
+                assert_eq!(
+                    Ontology::from_prefix_or_synonym(&synonym.to_uppercase()),
+                    Some(ontology),
+                    "{synonym}"
+                );
+            }
+        }
+        assert_eq!(Ontology::from_prefix(""), None);
+        assert_eq!(Ontology::from_prefix("HP:"), None);
+    }
+
+    #[test]
+    fn test_from_prefix_or_synonym() {
+        assert_eq!(
+            Ontology::from_prefix_or_synonym("FBBT"),
+            Some(Ontology::Fbbt)
+        );
+        assert_eq!(
+            Ontology::from_prefix_or_synonym("msh"),
+            Some(Ontology::Mesh)
+        );
+        assert_eq!(Ontology::from_prefix_or_synonym("HP_O"), Some(Ontology::Hp));
+        assert_eq!(Ontology::from_prefix_or_synonym("general"), None);
+        assert_eq!(Ontology::from_prefix("MIM"), None);
+    }
+
+    #[test]
+    fn test_validator_matches_metadata() {
+        let validator = Ontology::ApolloSv.validator();
+        assert_eq!(validator.metadata(), Some(Ontology::ApolloSv.metadata()));
+        assert_eq!(Ontology::ApolloSv.prefix(), "APOLLO_SV");
+    }
+}
@@ -0,0 +1,325 @@
+use crate::curie::{Curie, CurieRef};
+use crate::ontology::Ontology;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+/// Marks a prefix that is interned instead of being the index of an [`Ontology`].
+const INTERNED_PREFIX: u32 = 1 << 31;
+
+/// The most digits a numeric reference can have. Longer references are interned.
+const MAX_DIGITS: usize = 19;
+
+/// A CURIE packed into 16 bytes, for holding many CURIEs in memory.
+///
+/// The preferred prefix of a supported ontology is stored as the index of its [`Ontology`],
+/// and a reference of up to 19 digits as a number and its digit count, so that leading
+/// zeros survive. Other prefixes and references are interned by the [`PackedCurieArena`]
+/// that packed the CURIE, and referred to by their index.
+///
+/// A `PackedCurie` is only meaningful for the arena that packed it, which is needed to
+/// convert it back to a [`Curie`], display it or order it. Two `PackedCurie`s of the same
+/// arena are equal if and only if their [`Curie`]s are equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PackedCurie {
+    /// The numeric reference, or the index of the interned reference if `digits` is 0.
+    reference: u64,
+    /// The index of the ontology, or of the interned prefix if `INTERNED_PREFIX` is set.
+    prefix: u32,
+    digits: u8,
+}
+
+impl PackedCurie {
+    /// The ontology of the prefix, if it is the preferred prefix of a supported ontology.
+    pub fn ontology(&self) -> Option<Ontology> {
+        match self.prefix & INTERNED_PREFIX {
+            0 => Some(Ontology::ALL[self.prefix as usize]),
+            _ => None,
+        }
+    }
+}
+
+/// Packs CURIEs into [`PackedCurie`]s, and stores the prefixes and references that do not
+/// fit into them.
+///
+/// Each distinct interned string is stored once, and freed when the arena is dropped. Use
+/// one arena per set of CURIEs, e.g. per loaded annotation file. Packing takes `&mut self`,
+/// while unpacking, displaying and comparing take `&self`, so a filled arena can be shared
+/// between threads without locking.
+///
+/// The methods that take a `PackedCurie` may panic or return a wrong CURIE if it was packed
+/// by another arena.
+///
+/// # Examples
+///
+/// ```
+/// use securiety::{CurieParser, CurieParsing, Ontology, PackedCurie, PackedCurieArena};
+///
+/// let mut arena = PackedCurieArena::new();
+/// let curie = CurieParser::hp().parse("HP:0000118").unwrap();
+/// let packed = arena.pack(&curie);
+///
+/// assert_eq!(std::mem::size_of::<PackedCurie>(), 16);
+/// assert_eq!(packed.ontology(), Some(Ontology::Hp));
+/// assert_eq!(arena.display(packed).to_string(), "HP:0000118");
+/// assert_eq!(arena.to_curie(packed), curie);
+/// assert!(arena.is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct PackedCurieArena {
+    prefixes: Strings,
+    references: Strings,
+}
+
+impl PackedCurieArena {
+    pub fn new() -> Self {
+        PackedCurieArena::default()
+    }
+
+    /// Packs a CURIE, interning its prefix and reference if they do not fit the
+    /// `PackedCurie`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if more than 2³¹ distinct prefixes or `u32::MAX` distinct references are
+    /// interned.
+    pub fn pack<'c>(&mut self, curie: impl Into<CurieRef<'c>>) -> PackedCurie {
+        let curie = curie.into();
+        let (prefix, reference) = (curie.prefix(), curie.reference());
+
+        let prefix = match Ontology::from_prefix(prefix) {
+            Some(ontology) if ontology.prefix() == prefix => ontology as u32,
+            _ => {
+                let index = self.prefixes.intern(prefix);
+                assert!(index < INTERNED_PREFIX, "too many interned prefixes");
+                index | INTERNED_PREFIX
+            }
+        };
+
+        let is_numeric = !reference.is_empty()
+            && reference.len() <= MAX_DIGITS
+            && reference.bytes().all(|b| b.is_ascii_digit());
+        match is_numeric {
+            true => PackedCurie {
+                reference: reference
+                    .parse()
+                    .expect("references of 19 digits fit a u64"),
+                prefix,
+                digits: reference.len() as u8,
+            },
+            false => PackedCurie {
+                reference: u64::from(self.references.intern(reference)),
+                prefix,
+                digits: 0,
+            },
+        }
+    }
+
+    pub fn prefix(&self, packed: PackedCurie) -> &str {
+        match packed.ontology() {
+            Some(ontology) => ontology.prefix(),
+            None => self.prefixes.resolve(packed.prefix & !INTERNED_PREFIX),
+        }
+    }
+
+    /// Unpacks a CURIE.
+    pub fn to_curie(&self, packed: PackedCurie) -> Curie {
+        self.with_reference(packed, |reference| {
+            Curie::new(self.prefix(packed), reference)
+        })
+    }
+
+    /// Displays a packed CURIE like the [`Curie`] it packs, without unpacking it.
+    pub fn display(&self, packed: PackedCurie) -> DisplayPacked<'_> {
+        DisplayPacked {
+            arena: self,
+            packed,
+        }
+    }
+
+    /// Orders packed CURIEs like the [`Curie`]s they pack, e.g. for
+    /// `curies.sort_by(|a, b| arena.cmp(a, b))`.
+    pub fn cmp(&self, a: &PackedCurie, b: &PackedCurie) -> Ordering {
+        if a.prefix != b.prefix {
+            // Prefixes contain no colon, so the CURIEs differ before either reference starts.
+            let prefix = self.prefix(*a).bytes().chain(Some(b':'));
+            return prefix.cmp(self.prefix(*b).bytes().chain(Some(b':')));
+        }
+        if a.digits != 0 && a.digits == b.digits {
+            return a.reference.cmp(&b.reference);
+        }
+        self.with_reference(*a, |a| self.with_reference(*b, |b| a.cmp(b)))
+    }
+
+    /// The number of distinct prefixes and references interned.
+    pub fn len(&self) -> usize {
+        self.prefixes.strings.len() + self.references.strings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Calls `f` with the reference, which is formatted on the stack if it is numeric.
+    fn with_reference<R>(&self, packed: PackedCurie, f: impl FnOnce(&str) -> R) -> R {
+        if packed.digits == 0 {
+            return f(self.references.resolve(packed.reference as u32));
+        }
+
+        let mut buffer = [b'0'; MAX_DIGITS];
+        let digits = &mut buffer[..usize::from(packed.digits)];
+        let mut value = packed.reference;
+        for digit in digits.iter_mut().rev() {
+            *digit = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+        f(std::str::from_utf8(digits).expect("digits are ASCII"))
+    }
+}
+
+/// Displays a [`PackedCurie`], see [`PackedCurieArena::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayPacked<'a> {
+    arena: &'a PackedCurieArena,
+    packed: PackedCurie,
+}
+
+impl Display for DisplayPacked<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let prefix = self.arena.prefix(self.packed);
+        self.arena
+            .with_reference(self.packed, |reference| write!(f, "{prefix}:{reference}"))
+    }
+}
+
+/// Interned strings and their indices.
+#[derive(Debug, Default)]
+struct Strings {
+    strings: Vec<Arc<str>>,
+    indices: HashMap<Arc<str>, u32>,
+}
+
+impl Strings {
+    /// Returns the index of an interned string, interning it first if needed.
+    fn intern(&mut self, string: &str) -> u32 {
+        if let Some(&index) = self.indices.get(string) {
+            return index;
+        }
+        let index = u32::try_from(self.strings.len()).expect("too many interned strings");
+        let string: Arc<str> = Arc::from(string);
+        self.strings.push(Arc::clone(&string));
+        self.indices.insert(string, index);
+        index
+    }
+
+    fn resolve(&self, index: u32) -> &str {
+        &self.strings[index as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packed(arena: &mut PackedCurieArena, prefix: &str, reference: &str) -> PackedCurie {
+        arena.pack(&Curie::new(prefix, reference))
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut arena = PackedCurieArena::new();
+        for (prefix, reference) in [
+            ("HP", "0000118"),
+            ("HP", "0"),
+            ("HP", ""),
+            ("hp", "0000118"),
+            ("MESH", "D009369"),
+            ("custom", "ref:with:colons"),
+            ("NCBITaxon", "9606"),
+            ("ex", "18446744073709551615"),
+            ("ex", "9999999999999999999"),
+        ] {
+            let curie = Curie::new(prefix, reference);
+            let packed = arena.pack(&curie);
+            assert_eq!(arena.to_curie(packed), curie);
+            assert_eq!(arena.display(packed).to_string(), curie.to_string());
+        }
+    }
+
+    #[test]
+    fn test_packs_empty_reference() {
+        use crate::CurieParser;
+        use crate::traits::CurieParsing;
+        use crate::validators::combinators::FnValidator;
+
+        let curie = CurieParser::new(FnValidator(|_: &str| true))
+            .parse("HP:")
+            .unwrap();
+        let mut arena = PackedCurieArena::new();
+        let packed = arena.pack(&curie);
+
+        assert_eq!(packed.digits, 0);
+        assert_eq!(arena.to_curie(packed), curie);
+        assert_eq!(arena.display(packed).to_string(), "HP:");
+    }
+
+    #[test]
+    fn test_packs_numeric_references() {
+        let mut arena = PackedCurieArena::new();
+        let curie = packed(&mut arena, "HP", "0000118");
+        assert_eq!(curie.ontology(), Some(Ontology::Hp));
+        assert_eq!((curie.reference, curie.digits), (118, 7));
+        assert!(arena.is_empty());
+
+        assert_eq!(packed(&mut arena, "hp", "0000118").ontology(), None);
+        assert_eq!(packed(&mut arena, "ex", "18446744073709551615").digits, 0);
+        assert_eq!(arena.len(), 3);
+    }
+
+    #[test]
+    fn test_eq_matches_curie() {
+        let mut arena = PackedCurieArena::new();
+        let mut packed = |prefix, reference| packed(&mut arena, prefix, reference);
+
+        assert_eq!(packed("HP", "0000118"), packed("HP", "0000118"));
+        assert_eq!(packed("MESH", "D009369"), packed("MESH", "D009369"));
+        assert_ne!(packed("HP", "0000118"), packed("HP", "118"));
+        assert_ne!(packed("HP", "0000118"), packed("hp", "0000118"));
+        assert_eq!(arena.len(), 2);
+    }
+
+    #[test]
+    fn test_ord_matches_curie() {
+        let curies = [
+            ("HP", "0000118"),
+            ("HP", "118"),
+            ("HP", "12"),
+            ("HP", "A1"),
+            ("HP", "0000119"),
+            ("HP", ""),
+            ("HP-X", "1"),
+            ("HPX", "1"),
+            ("GO", "0008150"),
+            ("MESH", "D009369"),
+            ("MESH", "123"),
+        ];
+        let mut arena = PackedCurieArena::new();
+        let packed: Vec<_> = curies
+            .iter()
+            .map(|(prefix, reference)| packed(&mut arena, prefix, reference))
+            .collect();
+
+        for (a, b) in (0..curies.len()).flat_map(|a| (0..curies.len()).map(move |b| (a, b))) {
+            let expected =
+                Curie::new(curies[a].0, curies[a].1).cmp(&Curie::new(curies[b].0, curies[b].1));
+            assert_eq!(
+                arena.cmp(&packed[a], &packed[b]),
+                expected,
+                "{:?} {:?}",
+                curies[a],
+                curies[b]
+            );
+        }
+    }
+}
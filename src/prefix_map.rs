@@ -0,0 +1,227 @@
+use crate::curie::Curie;
+use crate::curie_parser::CurieParser;
+use crate::traits::CurieParsing;
+use crate::validators::curie_patterns::ONTOLOGIES;
+use std::collections::HashMap;
+
+/// A mapping from CURIE prefixes to URI prefixes.
+///
+/// A `PrefixMap` is used to expand a [`Curie`] into its full IRI, by appending the
+/// reference to the URI prefix registered for the CURIE's prefix, and to compress
+/// an IRI back into a [`Curie`].
+///
+/// # Examples
+///
+/// ```
+/// use securiety::{CurieParser, CurieParsing, PrefixMap};
+///
+/// let prefix_map = PrefixMap::bioregistry();
+/// let curie = CurieParser::hp().parse("HP:0000118").unwrap();
+///
+/// assert_eq!(
+///     prefix_map.expand(&curie).as_deref(),
+///     Some("http://purl.obolibrary.org/obo/HP_0000118")
+/// );
+/// assert_eq!(
+///     prefix_map.compress("http://purl.obolibrary.org/obo/HP_0000118"),
+///     Some(curie)
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrefixMap {
+    uri_prefixes: HashMap<String, String>,
+}
+
+impl PrefixMap {
+    /// Creates an empty `PrefixMap`.
+    pub fn new() -> PrefixMap {
+        PrefixMap::default()
+    }
+
+    /// Creates a `PrefixMap` seeded with the URI prefixes of all ontologies known to the
+    /// Bioregistry data this crate was generated from.
+    ///
+    /// Ontologies without a known URI format are not part of the map.
+    pub fn bioregistry() -> PrefixMap {
+        let uri_prefixes = ONTOLOGIES
+            .iter()
+            .filter_map(|metadata| {
+                let uri_prefix = metadata.uri_format()?.strip_suffix("$1")?;
+                Some((metadata.prefix().to_string(), uri_prefix.to_string()))
+            })
+            .collect();
+        PrefixMap { uri_prefixes }
+    }
+
+    /// Registers `uri_prefix` for `prefix`, returning the previously registered URI prefix, if any.
+    pub fn insert(&mut self, prefix: &str, uri_prefix: &str) -> Option<String> {
+        self.uri_prefixes
+            .insert(prefix.to_string(), uri_prefix.to_string())
+    }
+
+    /// Returns the URI prefix registered for `prefix`.
+    ///
+    /// The lookup is case-sensitive, matching how prefixes appear in a [`Curie`].
+    pub fn get(&self, prefix: &str) -> Option<&str> {
+        self.uri_prefixes.get(prefix).map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.uri_prefixes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.uri_prefixes.is_empty()
+    }
+
+    /// Expands a [`Curie`] into its full IRI.
+    ///
+    /// Returns `None` if no URI prefix is registered for the CURIE's prefix.
+    pub fn expand(&self, curie: &Curie) -> Option<String> {
+        let uri_prefix = self.get(curie.prefix())?;
+        Some(format!("{uri_prefix}{}", curie.reference()))
+    }
+
+    /// Compresses an IRI into a validated [`Curie`].
+    ///
+    /// The IRI is matched against the longest URI prefix it starts with. The resulting
+    /// CURIE is then validated by the parser of the matched prefix, see
+    /// [`CurieParser::from_prefix`]. Prefixes without a dedicated parser are validated
+    /// with [`CurieParser::general`].
+    ///
+    /// Returns `None` if no URI prefix matches, or if the resulting CURIE is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use securiety::PrefixMap;
+    /// let prefix_map = PrefixMap::bioregistry();
+    ///
+    /// let curie = prefix_map.compress("http://purl.obolibrary.org/obo/MONDO_0006007").unwrap();
+    /// assert_eq!(curie.to_string(), "MONDO:0006007");
+    ///
+    /// // The reference does not match the MONDO pattern
+    /// assert!(prefix_map.compress("http://purl.obolibrary.org/obo/MONDO_6007").is_none());
+    /// ```
+    pub fn compress(&self, iri: &str) -> Option<Curie> {
+        let (prefix, uri_prefix) = self
+            .uri_prefixes
+            .iter()
+            .filter(|(_, uri_prefix)| iri.starts_with(uri_prefix.as_str()))
+            .max_by(|(prefix_a, uri_prefix_a), (prefix_b, uri_prefix_b)| {
+                uri_prefix_a
+                    .len()
+                    .cmp(&uri_prefix_b.len())
+                    .then_with(|| prefix_b.cmp(prefix_a))
+            })?;
+
+        let reference = &iri[uri_prefix.len()..];
+        let parser = CurieParser::from_prefix(prefix).unwrap_or_else(CurieParser::general);
+        parser.parse(&format!("{prefix}:{reference}")).ok()
+    }
+}
+
+impl<P: Into<String>, U: Into<String>> FromIterator<(P, U)> for PrefixMap {
+    fn from_iter<T: IntoIterator<Item = (P, U)>>(iter: T) -> Self {
+        let uri_prefixes = iter
+            .into_iter()
+            .map(|(prefix, uri_prefix)| (prefix.into(), uri_prefix.into()))
+            .collect();
+        PrefixMap { uri_prefixes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_with_registered_prefix() {
+        let prefix_map = PrefixMap::from_iter([("ex", "http://example.org/")]);
+        let curie = Curie::new("ex", "123");
+
+        assert_eq!(
+            prefix_map.expand(&curie).as_deref(),
+            Some("http://example.org/123")
+        );
+    }
+
+    #[test]
+    fn test_expand_with_unknown_prefix() {
+        let prefix_map = PrefixMap::new();
+        let curie = Curie::new("ex", "123");
+
+        assert_eq!(prefix_map.expand(&curie), None);
+    }
+
+    #[test]
+    fn test_expand_is_case_sensitive() {
+        let prefix_map = PrefixMap::bioregistry();
+        let curie = Curie::new("hp", "0000118");
+
+        assert_eq!(prefix_map.expand(&curie), None);
+    }
+
+    #[test]
+    fn test_insert_overrides_bioregistry() {
+        let mut prefix_map = PrefixMap::bioregistry();
+        let previous = prefix_map.insert("HP", "https://hpo.jax.org/browse/term/HP:");
+
+        assert_eq!(
+            previous.as_deref(),
+            Some("http://purl.obolibrary.org/obo/HP_")
+        );
+        assert_eq!(
+            prefix_map.expand(&Curie::new("HP", "0000118")).as_deref(),
+            Some("https://hpo.jax.org/browse/term/HP:0000118")
+        );
+    }
+
+    #[test]
+    fn test_compress_uses_longest_uri_prefix() {
+        let prefix_map = PrefixMap::from_iter([
+            ("ex", "http://example.org/"),
+            ("sub", "http://example.org/sub/"),
+        ]);
+
+        let curie = prefix_map.compress("http://example.org/sub/123").unwrap();
+        assert_eq!(curie.prefix(), "sub");
+        assert_eq!(curie.reference(), "123");
+
+        let curie = prefix_map.compress("http://example.org/other/123").unwrap();
+        assert_eq!(curie.prefix(), "ex");
+        assert_eq!(curie.reference(), "other/123");
+    }
+
+    #[test]
+    fn test_compress_validates_with_ontology_pattern() {
+        let prefix_map = PrefixMap::bioregistry();
+
+        let curie = prefix_map
+            .compress("http://purl.obolibrary.org/obo/HP_0000118")
+            .unwrap();
+        assert_eq!(curie, Curie::new("HP", "0000118"));
+
+        assert!(
+            prefix_map
+                .compress("http://purl.obolibrary.org/obo/HP_118")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_compress_without_match() {
+        let prefix_map = PrefixMap::bioregistry();
+
+        assert!(prefix_map.compress("https://example.org/123").is_none());
+        assert!(prefix_map.compress("https://omim.org/entry/").is_none());
+    }
+
+    #[test]
+    fn test_bioregistry_skips_unknown_uri_formats() {
+        let prefix_map = PrefixMap::bioregistry();
+
+        assert!(prefix_map.get("CCF").is_none());
+        assert_eq!(prefix_map.get("OMIM"), Some("https://omim.org/entry/"));
+    }
+}
@@ -0,0 +1,306 @@
+use crate::curie::{Curie, CurieRef};
+use crate::error::{CurieParsingError, InvalidCurieReason, UnknownPrefixError};
+use crate::normalization::PrefixNormalization;
+use crate::ontology::Ontology;
+use crate::traits::CurieParsing;
+use crate::validators::curie_patterns::{GENERAL_PATTERN, general_regex};
+use crate::validators::diagnosis::{diagnose_ontology, diagnose_structure};
+use std::borrow::Cow;
+
+/// Determines how a [`RegistryParser`] handles CURIEs whose prefix has no generated validator.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum UnknownPrefixPolicy {
+    /// Reject the CURIE with [`CurieParsingError::UnknownPrefix`], which suggests the closest
+    /// supported prefixes.
+    #[default]
+    Reject,
+    /// Validate the CURIE with the general pattern, like [`CurieParser::general`](crate::CurieParser::general).
+    General,
+    /// Validate the CURIE with the general pattern, and pass every CURIE accepted this way
+    /// to the given function, e.g. to log it. The CURIE borrows from the input, so warning
+    /// does not allocate.
+    Warn(fn(CurieRef<'_>)),
+}
+
+/// A parser that validates each CURIE against the pattern of the ontology its prefix refers to.
+///
+/// The prefix is everything before the first colon (`:`) and is resolved like
+/// [`Ontology::from_prefix`]. This makes it possible to parse a column that mixes CURIEs of
+/// several ontologies, without choosing a [`CurieParser`](crate::CurieParser) per row.
+///
+/// # Examples
+///
+/// ```
+/// use securiety::{CurieParsing, RegistryParser, UnknownPrefixPolicy};
+///
+/// let parser = RegistryParser::default();
+/// assert!(parser.parse("HP:0000118").is_ok());
+/// assert!(parser.parse("MONDO:0006007").is_ok());
+/// assert!(parser.parse("MONDO:6007").is_err());
+/// assert!(parser.parse("custom:123").is_err());
+///
+/// let parser = RegistryParser::new(UnknownPrefixPolicy::General);
+/// assert!(parser.parse("custom:123").is_ok());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RegistryParser {
+    unknown_prefix_policy: UnknownPrefixPolicy,
+    prefix_normalization: PrefixNormalization,
+}
+
+impl RegistryParser {
+    pub fn new(unknown_prefix_policy: UnknownPrefixPolicy) -> RegistryParser {
+        RegistryParser {
+            unknown_prefix_policy,
+            prefix_normalization: PrefixNormalization::Exact,
+        }
+    }
+
+    /// Sets how the prefix of a CURIE is normalized before it is routed and validated.
+    ///
+    /// With [`PrefixNormalization::Synonyms`], CURIEs with a synonym prefix are routed to
+    /// the ontology of the synonym, e.g. `MIM:603903` to OMIM.
+    pub fn with_prefix_normalization(mut self, prefix_normalization: PrefixNormalization) -> Self {
+        self.prefix_normalization = prefix_normalization;
+        self
+    }
+
+    pub fn unknown_prefix_policy(&self) -> UnknownPrefixPolicy {
+        self.unknown_prefix_policy
+    }
+
+    pub fn prefix_normalization(&self) -> PrefixNormalization {
+        self.prefix_normalization
+    }
+
+    fn parse_general(curie: &str) -> Result<CurieRef<'_>, CurieParsingError> {
+        match general_regex().is_match(curie) {
+            true => Ok(split(curie)),
+            false => Err(CurieParsingError::InvalidCurie {
+                curie: curie.to_string(),
+                reason: diagnose_structure(curie).unwrap_or_else(|| {
+                    InvalidCurieReason::PatternMismatch {
+                        pattern: GENERAL_PATTERN.to_string(),
+                    }
+                }),
+            }),
+        }
+    }
+}
+
+fn split(curie: &str) -> CurieRef<'_> {
+    let (prefix, reference) = curie
+        .split_once(':')
+        .expect("validated CURIEs contain a colon");
+    CurieRef::new(prefix, reference)
+}
+
+/// A CURIE whose prefix was normalized and resolved to the ontology it refers to.
+///
+/// Shared by the parsers that route CURIEs by prefix, so that they normalize, route and
+/// validate alike.
+pub(crate) struct Routed<'a> {
+    input: &'a str,
+    curie: Cow<'a, str>,
+    ontology: Option<Ontology>,
+}
+
+impl<'a> Routed<'a> {
+    /// Normalizes the prefix of the input and resolves it like [`Ontology::from_prefix`].
+    pub(crate) fn new(
+        input: &'a str,
+        prefix_normalization: PrefixNormalization,
+    ) -> Result<Routed<'a>, CurieParsingError> {
+        let curie = prefix_normalization.normalize(input);
+        let Some((prefix, _)) = curie.split_once(':') else {
+            return Err(CurieParsingError::InvalidCurie {
+                curie: input.to_string(),
+                reason: InvalidCurieReason::MissingSeparator,
+            });
+        };
+        let ontology = Ontology::from_prefix(prefix);
+        Ok(Routed {
+            input,
+            curie,
+            ontology,
+        })
+    }
+
+    /// The normalized prefix.
+    pub(crate) fn prefix(&self) -> &str {
+        split(&self.curie).prefix()
+    }
+
+    /// The ontology the prefix refers to, or `None` if it has no generated validator.
+    pub(crate) fn ontology(&self) -> Option<Ontology> {
+        self.ontology
+    }
+
+    /// Validates the normalized CURIE against the pattern of the ontology, borrowing the
+    /// reference from the input.
+    pub(crate) fn validate(&self, ontology: Ontology) -> Result<CurieRef<'a>, CurieParsingError> {
+        match ontology.regex().is_match(&self.curie) {
+            true => Ok(CurieRef::new(
+                ontology.prefix(),
+                split(self.input).reference(),
+            )),
+            false => Err(CurieParsingError::InvalidCurie {
+                curie: self.input.to_string(),
+                reason: diagnose_ontology(ontology.metadata(), &self.curie),
+            }),
+        }
+    }
+}
+
+impl CurieParsing for RegistryParser {
+    /// Parses a CURIE with the validator of the ontology its prefix refers to.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Curie)` - Successfully parsed CURIE
+    /// * `Err(CurieParsingError::InvalidCurie)` - The input has no colon, or failed validation
+    /// * `Err(CurieParsingError::UnknownPrefix)` - The prefix has no generated validator and
+    ///   the policy is [`UnknownPrefixPolicy::Reject`]
+    fn parse(&self, input: &str) -> Result<Curie, CurieParsingError> {
+        self.parse_ref(input).map(CurieRef::to_owned)
+    }
+
+    /// Parses a CURIE like [`parse`](Self::parse), borrowing the reference from the input and
+    /// the prefix from the input or, for supported ontologies, the Bioregistry metadata.
+    fn parse_ref<'a>(&self, input: &'a str) -> Result<CurieRef<'a>, CurieParsingError> {
+        let routed = Routed::new(input, self.prefix_normalization)?;
+        if let Some(ontology) = routed.ontology() {
+            return routed.validate(ontology);
+        }
+
+        match self.unknown_prefix_policy {
+            UnknownPrefixPolicy::Reject => Err(UnknownPrefixError::new(routed.prefix()).into()),
+            UnknownPrefixPolicy::General => Self::parse_general(input),
+            UnknownPrefixPolicy::Warn(warn) => {
+                let parsed = Self::parse_general(input)?;
+                warn(parsed);
+                Ok(parsed)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_routes_by_prefix() {
+        let parser = RegistryParser::default();
+
+        let curie = parser.parse("OMIM:603903").unwrap();
+        assert_eq!(curie.prefix(), "OMIM");
+        assert_eq!(curie.reference(), "603903");
+
+        assert!(parser.parse("HP:0000118").is_ok());
+        assert!(parser.parse("ORDO:558").is_ok());
+    }
+
+    #[test]
+    fn test_normalizes_prefix_before_routing() {
+        let parser =
+            RegistryParser::default().with_prefix_normalization(PrefixNormalization::Synonyms);
+
+        assert_eq!(
+            parser.parse("MIM:603903").unwrap(),
+            Curie::new("OMIM", "603903")
+        );
+        assert_eq!(
+            parser.parse("fbbt:00000001").unwrap(),
+            Curie::new("FBbt", "00000001")
+        );
+        assert!(RegistryParser::default().parse("fbbt:00000001").is_err());
+    }
+
+    #[test]
+    fn test_rejects_invalid_reference() {
+        let parser = RegistryParser::default();
+
+        match parser.parse("HP:118") {
+            Err(CurieParsingError::InvalidCurie { curie, reason }) => {
+                assert_eq!(curie, "HP:118");
+                assert!(matches!(
+                    reason,
+                    InvalidCurieReason::ReferenceMismatch { .. }
+                ));
+            }
+            result => panic!("Expected InvalidCurie error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_rejects_missing_colon() {
+        let parser = RegistryParser::new(UnknownPrefixPolicy::General);
+
+        assert!(matches!(
+            parser.parse("HP0000118"),
+            Err(CurieParsingError::InvalidCurie {
+                reason: InvalidCurieReason::MissingSeparator,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_unknown_prefix() {
+        let parser = RegistryParser::new(UnknownPrefixPolicy::Reject);
+
+        match parser.parse("custom:123") {
+            Err(CurieParsingError::UnknownPrefix(error)) => assert_eq!(error.prefix(), "custom"),
+            result => panic!("Expected UnknownPrefix error, got {:?}", result),
+        }
+
+        match parser.parse("HPO:0000118") {
+            Err(CurieParsingError::UnknownPrefix(error)) => {
+                assert_eq!(error.suggestions()[0], "HP")
+            }
+            result => panic!("Expected UnknownPrefix error, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_falls_back_to_general() {
+        let parser = RegistryParser::new(UnknownPrefixPolicy::General);
+
+        assert_eq!(
+            parser.parse("custom:123").unwrap(),
+            Curie::new("custom", "123")
+        );
+        assert!(parser.parse("custom: 123").is_err());
+    }
+
+    #[test]
+    fn test_warns_on_general_fallback() {
+        static WARNINGS: AtomicUsize = AtomicUsize::new(0);
+        let parser = RegistryParser::new(UnknownPrefixPolicy::Warn(|curie| {
+            assert_eq!(curie, CurieRef::new("custom", "123"));
+            WARNINGS.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        assert!(parser.parse("custom:123").is_ok());
+        assert!(parser.parse("HP:0000118").is_ok());
+        assert!(parser.parse("custom: 123").is_err());
+        assert_eq!(WARNINGS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_parse_ref_borrows_input() {
+        let parser =
+            RegistryParser::default().with_prefix_normalization(PrefixNormalization::Synonyms);
+        let input = String::from("MIM:603903");
+
+        let curie = parser.parse_ref(&input).unwrap();
+        assert_eq!(curie.prefix(), "OMIM");
+        assert!(std::ptr::eq(curie.reference(), &input[4..]));
+
+        let parser = RegistryParser::new(UnknownPrefixPolicy::General);
+        let curie = parser.parse_ref("custom:123").unwrap();
+        assert_eq!(curie, CurieRef::new("custom", "123"));
+    }
+}
@@ -0,0 +1,81 @@
+//! Serde helpers that only accept CURIEs of one ontology.
+//!
+//! Every supported ontology has a module for `#[serde(with = "...")]`, named like its
+//! [`CurieParser`](crate::CurieParser) constructor. Serialization writes the CURIE as a string,
+//! and deserialization fails unless the string matches the pattern of the ontology. The error
+//! message includes the reason the CURIE was rejected, see
+//! [`InvalidCurieReason`](crate::InvalidCurieReason).
+//!
+//! ```
+//! use securiety::Curie;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct PhenotypicFeature {
+//!     #[serde(with = "securiety::serde::hp")]
+//!     r#type: Curie,
+//!     #[serde(with = "securiety::serde::mondo")]
+//!     disease: Curie,
+//! }
+//!
+//! let json = r#"{"type": "HP:0000118", "disease": "MONDO:0006007"}"#;
+//! let feature: PhenotypicFeature = serde_json::from_str(json).unwrap();
+//! assert_eq!(feature.r#type.prefix(), "HP");
+//!
+//! let json = r#"{"type": "HP:118", "disease": "MONDO:0006007"}"#;
+//! let error = serde_json::from_str::<PhenotypicFeature>(json).err().unwrap();
+//! assert!(error.to_string().starts_with("Invalid CURIE: HP:118 (reference does not match"));
+//! ```
+//!
+//! This module requires the `serde` feature.
+
+use crate::curie::Curie;
+use crate::ontology::Ontology;
+use crate::traits::CurieParsing;
+use ::serde::Deserialize;
+
+pub use crate::validators::curie_patterns::serde_with::*;
+
+/// Deserializes a CURIE from a string, validating it against the pattern of the ontology.
+pub(crate) fn deserialize_ontology<'de, D: ::serde::Deserializer<'de>>(
+    ontology: Ontology,
+    deserializer: D,
+) -> Result<Curie, D::Error> {
+    let curie = String::deserialize(deserializer)?;
+    ontology
+        .parser()
+        .parse(&curie)
+        .map_err(::serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::serde::Serialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Disease {
+        #[serde(with = "crate::serde::omim")]
+        id: Curie,
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let json = r#"{"id":"OMIM:603903"}"#;
+        let disease: Disease = serde_json::from_str(json).unwrap();
+
+        assert_eq!(disease.id.to_string(), "OMIM:603903");
+        assert_eq!(serde_json::to_string(&disease).unwrap(), json);
+    }
+
+    #[test]
+    fn test_rejects_other_ontologies() {
+        let error = serde_json::from_str::<Disease>(r#"{"id":"MONDO:0006007"}"#).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("expected prefix `OMIM`, got `MONDO`")
+        );
+        assert!(serde_json::from_str::<Disease>(r#"{"id":603903}"#).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::validators::curie_patterns::ONTOLOGIES;
+
+/// The maximum number of prefixes suggested for an unknown prefix.
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Finds the preferred prefixes closest to an unknown prefix, best match first.
+///
+/// Leading and trailing punctuation is ignored (`MONDO_` → `MONDO`), synonyms match
+/// exactly (`HPO` → `HP`), and otherwise prefixes and synonyms within a small edit distance
+/// are suggested. Matching ignores case.
+pub(crate) fn suggest_prefixes(prefix: &str) -> Vec<&'static str> {
+    let prefix = prefix
+        .trim_matches(|c: char| !c.is_alphanumeric())
+        .to_lowercase();
+    if prefix.is_empty() {
+        return Vec::new();
+    }
+    let max_distance = (prefix.chars().count() / 3).max(1);
+
+    let mut suggestions: Vec<_> = ONTOLOGIES
+        .iter()
+        .filter_map(|metadata| {
+            let distance = std::iter::once(metadata.prefix())
+                .chain(metadata.synonyms().iter().copied())
+                .map(|name| edit_distance(&prefix, &name.to_lowercase()))
+                .min()?;
+            (distance <= max_distance).then_some((distance, metadata.prefix()))
+        })
+        .collect();
+
+    suggestions.sort();
+    suggestions
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, prefix)| prefix)
+        .collect()
+}
+
+/// The Levenshtein distance between two strings, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("mondo", "mondo"), 0);
+        assert_eq!(edit_distance("mondoo", "mondo"), 1);
+        assert_eq!(edit_distance("mnodo", "mondo"), 2);
+        assert_eq!(edit_distance("", "hp"), 2);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_suggests_synonyms_first() {
+        assert_eq!(suggest_prefixes("HPO")[0], "HP");
+        assert_eq!(suggest_prefixes("MIM")[0], "OMIM");
+        assert_eq!(suggest_prefixes("Orphanet"), ["ORDO"]);
+    }
+
+    #[test]
+    fn test_ignores_punctuation() {
+        assert_eq!(suggest_prefixes("MONDO_")[0], "MONDO");
+        assert_eq!(suggest_prefixes(" chebi: ")[0], "CHEBI");
+    }
+
+    #[test]
+    fn test_suggests_similar_prefixes() {
+        assert_eq!(suggest_prefixes("MONDOO")[0], "MONDO");
+        assert!(suggest_prefixes("UBERONN").contains(&"UBERON"));
+        assert!(suggest_prefixes("definitely_not_a_prefix").is_empty());
+        assert!(suggest_prefixes("__").is_empty());
+    }
+}
@@ -1,10 +1,130 @@
-use crate::curie::Curie;
-use crate::error::CurieParsingError;
+use crate::curie::{Curie, CurieRef};
+use crate::error::{CurieParsingError, InvalidCurieReason};
+use crate::lenient::{LenientCurie, Rewrite, rewrite, zero_pad};
+use crate::validators::diagnosis::diagnose_structure;
 
 pub trait CurieParsing {
     fn parse(&self, curie: &str) -> Result<Curie, CurieParsingError>;
+
+    /// Parses a CURIE into a [`CurieRef`] that borrows from the input instead of allocating.
+    ///
+    /// The parsers of this crate validate the input without copying it. The default
+    /// implementation parses the input with [`parse`](Self::parse) and borrows the parts of
+    /// the result from the input, so parsers that rewrite more than the prefix should
+    /// override it. It fails with [`CurieParsingError::UnparsableCurie`] if the parts cannot
+    /// be borrowed.
+    ///
+    /// ```
+    /// use securiety::{CurieParsing, PrefixNormalization, RegistryParser};
+    /// let parser = RegistryParser::default().with_prefix_normalization(PrefixNormalization::Case);
+    ///
+    /// let rows = "hp:0000118\nMONDO:0006007";
+    /// let curies: Vec<_> = rows.lines().map(|row| parser.parse_ref(row).unwrap()).collect();
+    /// assert_eq!(curies[0].to_string(), "HP:0000118");
+    /// assert_eq!(curies[1].prefix(), "MONDO");
+    /// ```
+    fn parse_ref<'a>(&self, curie: &'a str) -> Result<CurieRef<'a>, CurieParsingError> {
+        let parsed = self.parse(curie)?;
+        CurieRef::borrow_parsed(curie, &parsed)
+            .ok_or_else(|| CurieParsingError::UnparsableCurie(curie.to_string()))
+    }
+
+    /// Parses a CURIE after rewriting common spellings of it, such as `HP_0000118`,
+    /// `obo:HP_0000118`, `http://purl.obolibrary.org/obo/HP_0000118` or `<HP:0000118>`.
+    ///
+    /// The rewritten input is parsed with [`parse`](Self::parse), and the applied
+    /// [`Rewrite`]s are returned with the CURIE, e.g. to log them.
+    ///
+    /// ```
+    /// use securiety::{CurieParser, CurieParsing, Rewrite};
+    /// let parser = CurieParser::hp();
+    ///
+    /// let parsed = parser.parse_lenient("http://purl.obolibrary.org/obo/HP_0000118").unwrap();
+    /// assert_eq!(parsed.curie().to_string(), "HP:0000118");
+    /// assert_eq!(parsed.rewrites(), [Rewrite::OboPurl, Rewrite::Underscore]);
+    ///
+    /// assert!(parser.parse_lenient("HP:0000118").unwrap().rewrites().is_empty());
+    /// ```
+    fn parse_lenient(&self, curie: &str) -> Result<LenientCurie, CurieParsingError> {
+        let (rewritten, rewrites) = rewrite(curie);
+        let curie = self.parse(&rewritten)?;
+        Ok(LenientCurie::new(curie, rewrites))
+    }
+
+    /// Parses a CURIE, padding a numeric reference with leading zeros if it is rejected
+    /// because it is too short, e.g. `HP:118` after a spreadsheet stripped its zeros.
+    ///
+    /// The width is derived from the pattern of the ontology, see
+    /// [`OntologyMetadata::reference_width`](crate::OntologyMetadata::reference_width).
+    /// Padded CURIEs are reported with [`Rewrite::ZeroPadded`].
+    ///
+    /// ```
+    /// use securiety::{CurieParser, CurieParsing};
+    /// let parser = CurieParser::hp();
+    ///
+    /// let parsed = parser.parse_zero_padded("HP:118").unwrap();
+    /// assert_eq!(parsed.curie().to_string(), "HP:0000118");
+    /// assert!(parsed.is_modified());
+    ///
+    /// assert!(!parser.parse_zero_padded("HP:0000118").unwrap().is_modified());
+    /// ```
+    fn parse_zero_padded(&self, curie: &str) -> Result<LenientCurie, CurieParsingError> {
+        let error = match self.parse(curie) {
+            Ok(parsed) => return Ok(LenientCurie::new(parsed, Vec::new())),
+            Err(error) => error,
+        };
+        match zero_pad(curie).map(|padded| self.parse(&padded)) {
+            Some(Ok(parsed)) => Ok(LenientCurie::new(parsed, vec![Rewrite::ZeroPadded])),
+            _ => Err(error),
+        }
+    }
 }
 
 pub trait CurieValidation {
     fn validate(&self, curie: &str) -> bool;
+
+    /// Explains why a CURIE is rejected by this validator.
+    ///
+    /// Returns `None` if the CURIE is valid. The default implementation reports structural
+    /// problems, such as a missing colon, and [`InvalidCurieReason::Rejected`] otherwise.
+    ///
+    /// ```
+    /// use securiety::{CurieRegexValidator, CurieValidation, InvalidCurieReason};
+    /// let validator = CurieRegexValidator::hp();
+    ///
+    /// assert_eq!(validator.explain("HP:0000118"), None);
+    /// assert_eq!(
+    ///     validator.explain("hp:0000118"),
+    ///     Some(InvalidCurieReason::PrefixMismatch {
+    ///         expected: "HP",
+    ///         found: "hp".to_string(),
+    ///     })
+    /// );
+    /// ```
+    fn explain(&self, curie: &str) -> Option<InvalidCurieReason> {
+        match self.validate(curie) {
+            true => None,
+            false => Some(diagnose_structure(curie).unwrap_or(InvalidCurieReason::Rejected)),
+        }
+    }
+}
+
+impl<V: CurieValidation + ?Sized> CurieValidation for &V {
+    fn validate(&self, curie: &str) -> bool {
+        (**self).validate(curie)
+    }
+
+    fn explain(&self, curie: &str) -> Option<InvalidCurieReason> {
+        (**self).explain(curie)
+    }
+}
+
+impl<V: CurieValidation + ?Sized> CurieValidation for Box<V> {
+    fn validate(&self, curie: &str) -> bool {
+        (**self).validate(curie)
+    }
+
+    fn explain(&self, curie: &str) -> Option<InvalidCurieReason> {
+        (**self).explain(curie)
+    }
 }
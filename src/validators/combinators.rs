@@ -0,0 +1,285 @@
+//! Validators that combine other validators.
+//!
+//! Combinators make it possible to express project-specific rules from existing validators,
+//! without implementing [`CurieValidation`] for a new type.
+//!
+//! ```
+//! use securiety::{AllOf, CurieParser, CurieParsing, CurieRegexValidator, FnValidator, Not};
+//!
+//! const BLOCKED: &[&str] = &["HP:0000001", "HP:0000005"];
+//!
+//! let parser = CurieParser::new(AllOf((
+//!     CurieRegexValidator::hp(),
+//!     Not(FnValidator(|curie: &str| BLOCKED.contains(&curie))),
+//! )));
+//!
+//! assert!(parser.parse("HP:0000118").is_ok());
+//! assert!(parser.parse("HP:0000001").is_err());
+//! assert!(parser.parse("MONDO:0000001").is_err());
+//! ```
+//!
+//! [`AnyOf`] and [`AllOf`] accept tuples of up to eight validators of different types, as
+//! well as arrays, slices and `Vec`s of validators of the same type. An empty [`AnyOf`]
+//! accepts nothing, and an empty [`AllOf`] accepts everything.
+
+use crate::error::InvalidCurieReason;
+use crate::traits::CurieValidation;
+use std::fmt::{Debug, Formatter};
+
+/// Accepts a CURIE if any of the wrapped validators accepts it.
+///
+/// ```
+/// use securiety::{AnyOf, CurieRegexValidator, CurieValidation};
+///
+/// let validator = AnyOf([CurieRegexValidator::mondo(), CurieRegexValidator::omim()]);
+/// assert!(validator.validate("MONDO:0006007"));
+/// assert!(validator.validate("OMIM:603903"));
+/// assert!(!validator.validate("HP:0000118"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AnyOf<V>(pub V);
+
+/// Accepts a CURIE if all of the wrapped validators accept it.
+///
+/// A rejected CURIE is explained by the first validator that rejects it.
+///
+/// ```
+/// use securiety::{AllOf, CurieRegexValidator, CurieValidation, PrefixIs};
+///
+/// let validator = AllOf((CurieRegexValidator::general(), PrefixIs("HP")));
+/// assert!(validator.validate("HP:custom"));
+/// assert!(!validator.validate("MONDO:0006007"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct AllOf<V>(pub V);
+
+/// Accepts a CURIE if the wrapped validator rejects it.
+///
+/// ```
+/// use securiety::{CurieValidation, Not, PrefixIs};
+///
+/// let validator = Not(PrefixIs("HP"));
+/// assert!(validator.validate("MONDO:0006007"));
+/// assert!(!validator.validate("HP:0000118"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Not<V>(pub V);
+
+/// Accepts a CURIE if its prefix, i.e. everything before the first colon (`:`), equals the
+/// given prefix.
+///
+/// The comparison is case-sensitive, and the reference is not checked. Combine it with
+/// [`AllOf`] to gate another validator on the prefix.
+///
+/// ```
+/// use securiety::{CurieValidation, PrefixIs};
+///
+/// let validator = PrefixIs("HP");
+/// assert!(validator.validate("HP:0000118"));
+/// assert!(!validator.validate("hp:0000118"));
+/// assert!(!validator.validate("HP0000118"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrefixIs<P = &'static str>(pub P);
+
+/// Accepts a CURIE if the wrapped function returns `true` for it.
+///
+/// ```
+/// use securiety::{CurieValidation, FnValidator};
+///
+/// let validator = FnValidator(|curie: &str| curie.len() <= 10);
+/// assert!(validator.validate("HP:0000118"));
+/// assert!(!validator.validate("MONDO:0006007"));
+/// ```
+#[derive(Clone, Copy)]
+pub struct FnValidator<F>(pub F);
+
+impl<V: CurieValidation> CurieValidation for AnyOf<Vec<V>> {
+    fn validate(&self, curie: &str) -> bool {
+        self.0.iter().any(|validator| validator.validate(curie))
+    }
+}
+
+impl<V: CurieValidation, const N: usize> CurieValidation for AnyOf<[V; N]> {
+    fn validate(&self, curie: &str) -> bool {
+        self.0.iter().any(|validator| validator.validate(curie))
+    }
+}
+
+impl<V: CurieValidation> CurieValidation for AnyOf<&[V]> {
+    fn validate(&self, curie: &str) -> bool {
+        self.0.iter().any(|validator| validator.validate(curie))
+    }
+}
+
+impl<V: CurieValidation> CurieValidation for AllOf<Vec<V>> {
+    fn validate(&self, curie: &str) -> bool {
+        self.0.iter().all(|validator| validator.validate(curie))
+    }
+
+    fn explain(&self, curie: &str) -> Option<InvalidCurieReason> {
+        self.0.iter().find_map(|validator| validator.explain(curie))
+    }
+}
+
+impl<V: CurieValidation, const N: usize> CurieValidation for AllOf<[V; N]> {
+    fn validate(&self, curie: &str) -> bool {
+        self.0.iter().all(|validator| validator.validate(curie))
+    }
+
+    fn explain(&self, curie: &str) -> Option<InvalidCurieReason> {
+        self.0.iter().find_map(|validator| validator.explain(curie))
+    }
+}
+
+impl<V: CurieValidation> CurieValidation for AllOf<&[V]> {
+    fn validate(&self, curie: &str) -> bool {
+        self.0.iter().all(|validator| validator.validate(curie))
+    }
+
+    fn explain(&self, curie: &str) -> Option<InvalidCurieReason> {
+        self.0.iter().find_map(|validator| validator.explain(curie))
+    }
+}
+
+macro_rules! impl_tuple_combinators {
+    ( $( ( $($name:ident $index:tt),+ ) ),* $(,)? ) => {
+        $(
+            impl<$($name: CurieValidation),+> CurieValidation for AnyOf<($($name,)+)> {
+                fn validate(&self, curie: &str) -> bool {
+                    $(self.0.$index.validate(curie))||+
+                }
+            }
+
+            impl<$($name: CurieValidation),+> CurieValidation for AllOf<($($name,)+)> {
+                fn validate(&self, curie: &str) -> bool {
+                    $(self.0.$index.validate(curie))&&+
+                }
+
+                fn explain(&self, curie: &str) -> Option<InvalidCurieReason> {
+                    None $(.or_else(|| self.0.$index.explain(curie)))+
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple_combinators!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+);
+
+impl<V: CurieValidation> CurieValidation for Not<V> {
+    fn validate(&self, curie: &str) -> bool {
+        !self.0.validate(curie)
+    }
+}
+
+impl<P: AsRef<str>> CurieValidation for PrefixIs<P> {
+    fn validate(&self, curie: &str) -> bool {
+        curie
+            .split_once(':')
+            .is_some_and(|(prefix, _)| prefix == self.0.as_ref())
+    }
+}
+
+impl<F: Fn(&str) -> bool> CurieValidation for FnValidator<F> {
+    fn validate(&self, curie: &str) -> bool {
+        (self.0)(curie)
+    }
+}
+
+impl<F> Debug for FnValidator<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FnValidator").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCEPT: FnValidator<fn(&str) -> bool> = FnValidator(|_| true);
+    const REJECT: FnValidator<fn(&str) -> bool> = FnValidator(|_| false);
+
+    #[test]
+    fn test_any_of() {
+        assert!(AnyOf((REJECT, ACCEPT)).validate("HP:0000118"));
+        assert!(!AnyOf((REJECT, REJECT, REJECT)).validate("HP:0000118"));
+        assert!(AnyOf(vec![REJECT, ACCEPT]).validate("HP:0000118"));
+        assert!(!AnyOf(Vec::<FnValidator<fn(&str) -> bool>>::new()).validate("HP:0000118"));
+    }
+
+    #[test]
+    fn test_all_of() {
+        assert!(AllOf((ACCEPT, ACCEPT)).validate("HP:0000118"));
+        assert!(!AllOf((ACCEPT, REJECT, ACCEPT)).validate("HP:0000118"));
+        assert!(!AllOf([ACCEPT, REJECT].as_slice()).validate("HP:0000118"));
+        assert!(AllOf(Vec::<FnValidator<fn(&str) -> bool>>::new()).validate("HP:0000118"));
+    }
+
+    #[test]
+    fn test_all_of_explains_first_rejection() {
+        let validator = AllOf((ACCEPT, crate::CurieRegexValidator::hp(), REJECT));
+
+        assert_eq!(
+            validator.explain("HP:0000118"),
+            Some(InvalidCurieReason::Rejected)
+        );
+        assert!(matches!(
+            validator.explain("HP:118"),
+            Some(InvalidCurieReason::ReferenceMismatch { .. })
+        ));
+        assert_eq!(
+            AllOf(vec![ACCEPT, REJECT]).explain("HP0000118"),
+            Some(InvalidCurieReason::MissingSeparator)
+        );
+    }
+
+    #[test]
+    fn test_not() {
+        assert!(Not(REJECT).validate("HP:0000118"));
+        assert!(!Not(ACCEPT).validate("HP:0000118"));
+        assert!(Not(Not(ACCEPT)).validate("HP:0000118"));
+    }
+
+    #[test]
+    fn test_prefix_is() {
+        assert!(PrefixIs("HP").validate("HP:0000118"));
+        assert!(PrefixIs(String::from("HP")).validate("HP:0000118"));
+        assert!(!PrefixIs("HP").validate("HPO:0000118"));
+        assert!(!PrefixIs("HP").validate("MONDO:HP:0000118"));
+        assert!(!PrefixIs("").validate("HP0000118"));
+    }
+
+    #[test]
+    fn test_nested_combinators() {
+        let validator = AnyOf((
+            AllOf((
+                PrefixIs("HP"),
+                Not(FnValidator(|curie: &str| curie.ends_with('1'))),
+            )),
+            PrefixIs("MONDO"),
+        ));
+
+        assert!(validator.validate("HP:0000118"));
+        assert!(!validator.validate("HP:0000001"));
+        assert!(validator.validate("MONDO:0000001"));
+        assert!(!validator.validate("OMIM:0000001"));
+    }
+
+    #[test]
+    fn test_validators_by_reference() {
+        let validators: Vec<Box<dyn CurieValidation>> =
+            vec![Box::new(PrefixIs("HP")), Box::new(PrefixIs("MONDO"))];
+
+        assert!(AnyOf(&validators[..]).validate("MONDO:0006007"));
+        assert!(AllOf((&ACCEPT, &PrefixIs("HP"))).validate("HP:0000118"));
+    }
+}
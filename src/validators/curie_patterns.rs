@@ -1,419 +1,3459 @@
+//! CURIE patterns of the ontologies in the Bioregistry.
+//!
+//! @generated by `cargo xtask codegen` from `data/bioregistry.json` (no recorded Bioregistry release). Do not edit by hand.
+
 use crate::curie_parser::CurieParser;
+use crate::metadata::OntologyMetadata;
 use crate::validators::regex_validator::CurieRegexValidator;
 use paste::paste;
 use std::sync::OnceLock;
 
-macro_rules! define_curie_validators {
-    ( $( $fn_name:ident, $const_name:ident => $pattern:literal ),* $(,)? ) => {
-
-        $(
-            pub const $const_name: &'static str = $pattern;
-        )*
-        $(
-            paste! {
-                static [<$const_name _REGEX>]: OnceLock<regex::Regex> = OnceLock::new();
-            }
-        )*
-
-        impl CurieRegexValidator {
-            $(
-                paste! {
-                    pub fn $fn_name() -> Self {
-                        let regex = [<$const_name _REGEX>].get_or_init(|| {
-                            regex::Regex::new($const_name)
-                                .expect(concat!("Error compiling regex for ", stringify!($const_name)))
-                        });
-                        Self::from(regex.clone())
-                    }
-                }
-            )*
-
+/// The Bioregistry release or commit the patterns and metadata were generated from, or
+/// `None` if the snapshot does not record one.
+pub const BIOREGISTRY_RELEASE: Option<&str> = None;
 
-            pub fn from_prefix(prefix: &str) -> Option<Self> {
-                match prefix.to_lowercase().as_str() {
-                    $(
-                        stringify!($fn_name) => Some(Self::$fn_name()),
-                    )*
-                    _ => None,
-                }
-            }
-        }
+define_curie_validators! {
+    adcad, ADCAD_PATTERN => "^ADCAD:\\d{5}$" {
+        prefix: "ADCAD",
+        name: "Arctic Data Center Academic Disciplines Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("https://purl.dataone.org/odo/ADCAD_$1"),
+        license: None,
+    },
+    ado, ADO_PATTERN => "^ADO:\\d{7}$" {
+        prefix: "ADO",
+        name: "Alzheimer's Disease Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ADO_$1"),
+        license: None,
+    },
+    aeo, AEO_PATTERN => "^AEO:\\d{7}$" {
+        prefix: "AEO",
+        name: "Anatomical Entity Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/AEO_$1"),
+        license: None,
+    },
+    aero, AERO_PATTERN => "^AERO:\\d{7}$" {
+        prefix: "AERO",
+        name: "Adverse Event Reporting Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/AERO_$1"),
+        license: None,
+    },
+    agro, AGRO_PATTERN => "^AGRO:\\d{8}$" {
+        prefix: "AGRO",
+        name: "Agronomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/AGRO_$1"),
+        license: None,
+    },
+    agrovoc, AGROVOC_PATTERN => "^AGROVOC:[a-z0-9]+$" {
+        prefix: "AGROVOC",
+        name: "Agronomy Vocabulary",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://aims.fao.org/aos/agrovoc/c_$1"),
+        license: None,
+    },
+    aism, AISM_PATTERN => "^AISM:\\d{7}$" {
+        prefix: "AISM",
+        name: "Ontology for the Anatomy of the Insect SkeletoMuscular system",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/AISM_$1"),
+        license: None,
+    },
+    amphx, AMPHX_PATTERN => "^AMPHX:\\d+$" {
+        prefix: "AMPHX",
+        name: "The Amphioxus Development and Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/AMPHX_$1"),
+        license: None,
+    },
+    apo, APO_PATTERN => "^APO:\\d{7}$" {
+        prefix: "APO",
+        name: "Ascomycete phenotype ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/APO_$1"),
+        license: None,
+    },
+    apollo_sv, APOLLO_SV_PATTERN => "^APOLLO_SV:\\d{8}$" {
+        prefix: "APOLLO_SV",
+        name: "Apollo Structured Vocabulary",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/APOLLO_SV_$1"),
+        license: None,
+    },
+    aro, ARO_PATTERN => "^ARO:\\d{7}$" {
+        prefix: "ARO",
+        name: "Antibiotic Resistance Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ARO_$1"),
+        license: None,
+    },
+    ato, ATO_PATTERN => "^ATO:\\d{7}$" {
+        prefix: "ATO",
+        name: "Amphibian taxonomy",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ATO_$1"),
+        license: None,
+    },
+    atol, ATOL_PATTERN => "^ATOL:\\d{7}$" {
+        prefix: "ATOL",
+        name: "Animal Trait Ontology for Livestock",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ATOL_$1"),
+        license: None,
+    },
+    bcgo, BCGO_PATTERN => "^BCGO:\\d{7}$" {
+        prefix: "BCGO",
+        name: "Beta Cell Genomics Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    bcio, BCIO_PATTERN => "^BCIO:\\d{6}$" {
+        prefix: "BCIO",
+        name: "The Behaviour Change Intervention Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://humanbehaviourchange.org/ontology/BCIO_$1"),
+        license: None,
+    },
+    bco, BCO_PATTERN => "^BCO:\\d{7}$" {
+        prefix: "BCO",
+        name: "Biological Collections Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/BCO_$1"),
+        license: None,
+    },
+    bervo, BERVO_PATTERN => "^BERVO:\\d{7}$" {
+        prefix: "BERVO",
+        name: "Biological and Environmental Research Variable Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    bfo, BFO_PATTERN => "^BFO:\\d{7}$" {
+        prefix: "BFO",
+        name: "Basic Formal Ontology",
+        synonyms: &[],
+        description: Some("The upper level ontology upon which OBO Foundry ontologies are built."),
+        homepage: Some("http://ifomis.org/bfo/"),
+        example: Some("0000001"),
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/BFO_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    birnlex, BIRNLEX_PATTERN => "^BIRNLEX:\\d+$" {
+        prefix: "BIRNLEX",
+        name: "Biomedical Informatics Research Network Lexicon",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://uri.neuinfo.org/nif/nifstd/birnlex_$1"),
+        license: None,
+    },
+    bko, BKO_PATTERN => "^BKO:\\d+$" {
+        prefix: "BKO",
+        name: "SBGN Bricks data and ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    bspo, BSPO_PATTERN => "^BSPO:\\d{7}$" {
+        prefix: "BSPO",
+        name: "Biological Spatial Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/BSPO_$1"),
+        license: None,
+    },
+    bto, BTO_PATTERN => "^BTO:\\d{7}$" {
+        prefix: "BTO",
+        name: "BRENDA Tissue Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: Some("BTO:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/BTO_$1"),
+        license: None,
+    },
+    cao, CAO_PATTERN => "^CAO:\\d+$" {
+        prefix: "CAO",
+        name: "Chemical Analysis Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    caro, CARO_PATTERN => "^CARO:\\d{7}$" {
+        prefix: "CARO",
+        name: "Common Anatomy Reference Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CARO_$1"),
+        license: None,
+    },
+    ccf, CCF_PATTERN => "^CCF:\\S+$" {
+        prefix: "CCF",
+        name: "Human Reference Atlas Common Coordinate Framework Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    cco, CCO_PATTERN => "^CCO:\\w+$" {
+        prefix: "CCO",
+        name: "Cell Cycle Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CCO_$1"),
+        license: None,
+    },
+    cdao, CDAO_PATTERN => "^CDAO:\\d{7}$" {
+        prefix: "CDAO",
+        name: "Comparative Data Analysis Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CDAO_$1"),
+        license: None,
+    },
+    cdno, CDNO_PATTERN => "^CDNO:\\d{7}$" {
+        prefix: "CDNO",
+        name: "Compositional Dietary Nutrition Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CDNO_$1"),
+        license: None,
+    },
+    ceph, CEPH_PATTERN => "^CEPH:\\d{7}$" {
+        prefix: "CEPH",
+        name: "Cephalopod Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CEPH_$1"),
+        license: None,
+    },
+    chebi, CHEBI_PATTERN => "^CHEBI:\\d+$" {
+        prefix: "CHEBI",
+        name: "Chemical Entities of Biological Interest",
+        synonyms: &["CHEBIID"],
+        description: Some("Chemical Entities of Biological Interest (ChEBI) is a freely available dictionary of molecular entities focused on 'small' chemical compounds."),
+        homepage: Some("https://www.ebi.ac.uk/chebi"),
+        example: Some("24431"),
+        banana: Some("CHEBI:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/CHEBI_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    cheminf, CHEMINF_PATTERN => "^CHEMINF:\\d{6}$" {
+        prefix: "CHEMINF",
+        name: "Chemical Information Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CHEMINF_$1"),
+        license: None,
+    },
+    chmo, CHMO_PATTERN => "^CHMO:\\d{7}$" {
+        prefix: "CHMO",
+        name: "Chemical Methods Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CHMO_$1"),
+        license: None,
+    },
+    cido, CIDO_PATTERN => "^CIDO:\\d{7}$" {
+        prefix: "CIDO",
+        name: "Coronavirus Infectious Disease Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CIDO_$1"),
+        license: None,
+    },
+    cio, CIO_PATTERN => "^CIO:\\d{7}$" {
+        prefix: "CIO",
+        name: "Confidence Information Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CIO_$1"),
+        license: None,
+    },
+    cl, CL_PATTERN => "^CL:\\d{7}$" {
+        prefix: "CL",
+        name: "Cell Ontology",
+        synonyms: &[],
+        description: Some("The Cell Ontology is a structured controlled vocabulary for cell types in animals."),
+        homepage: Some("https://obophenotype.github.io/cell-ontology/"),
+        example: Some("0000000"),
+        banana: Some("CL:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/CL_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    clao, CLAO_PATTERN => "^CLAO:\\d{7}$" {
+        prefix: "CLAO",
+        name: "Collembola Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CLAO_$1"),
+        license: None,
+    },
+    clo, CLO_PATTERN => "^CLO:\\d{7}$" {
+        prefix: "CLO",
+        name: "Cell Line Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CLO_$1"),
+        license: None,
+    },
+    clyh, CLYH_PATTERN => "^CLYH:\\d+$" {
+        prefix: "CLYH",
+        name: "Clytia hemisphaerica Development and Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CLYH_$1"),
+        license: None,
+    },
+    cmecs, CMECS_PATTERN => "^CMECS:\\d+$" {
+        prefix: "CMECS",
+        name: "Costal and Marine Ecological Classification Standard",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    cmo, CMO_PATTERN => "^CMO:\\d{7}$" {
+        prefix: "CMO",
+        name: "Clinical measurement ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CMO_$1"),
+        license: None,
+    },
+    cmpo, CMPO_PATTERN => "^CMPO:\\d{7}$" {
+        prefix: "CMPO",
+        name: "Cellular Microscopy Phenotype Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CMPO_$1"),
+        license: None,
+    },
+    cob, COB_PATTERN => "^COB:\\d{7}$" {
+        prefix: "COB",
+        name: "Core Ontology for Biology and Biomedicine",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/COB_$1"),
+        license: None,
+    },
+    colao, COLAO_PATTERN => "^COLAO:\\d{7}$" {
+        prefix: "COLAO",
+        name: "Coleoptera Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/COLAO_$1"),
+        license: None,
+    },
+    cpt, CPT_PATTERN => "^CPT:\\d+$" {
+        prefix: "CPT",
+        name: "Current Procedural Terminology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("https://www.aapc.com/codes/cpt-codes/$1"),
+        license: None,
+    },
+    cro, CRO_PATTERN => "^CRO:\\d{7}$" {
+        prefix: "CRO",
+        name: "Contributor Role Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CRO_$1"),
+        license: None,
+    },
+    cryoem, CRYOEM_PATTERN => "^CRYOEM:\\d{7}$" {
+        prefix: "CRYOEM",
+        name: "Cryo Electron Microscopy ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CRYOEM_$1"),
+        license: None,
+    },
+    ctcae, CTCAE_PATTERN => "^CTCAE:E\\d+$" {
+        prefix: "CTCAE",
+        name: "Common Terminology Criteria for Adverse Events",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    cteno, CTENO_PATTERN => "^CTENO:\\d{7}$" {
+        prefix: "CTENO",
+        name: "Ctenophore Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CTENO_$1"),
+        license: None,
+    },
+    cto, CTO_PATTERN => "^CTO:\\d{7}$" {
+        prefix: "CTO",
+        name: "Clinical Trials Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CTO_$1"),
+        license: None,
+    },
+    cvdo, CVDO_PATTERN => "^CVDO:\\d{7}$" {
+        prefix: "CVDO",
+        name: "Cardiovascular Disease Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/CVDO_$1"),
+        license: None,
+    },
+    dcm, DCM_PATTERN => "^DCM:\\d+$" {
+        prefix: "DCM",
+        name: "DICOM Controlled Terminology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://dicom.nema.org/resources/ontology/DCM/$1"),
+        license: None,
+    },
+    ddanat, DDANAT_PATTERN => "^DDANAT:\\d{7}$" {
+        prefix: "DDANAT",
+        name: "Dictyostelium discoideum anatomy",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/DDANAT_$1"),
+        license: None,
+    },
+    ddpheno, DDPHENO_PATTERN => "^DDPHENO:\\d{7}$" {
+        prefix: "DDPHENO",
+        name: "Dictyostelium discoideum phenotype ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/DDPHENO_$1"),
+        license: None,
+    },
+    dermo, DERMO_PATTERN => "^DERMO:\\d{7}$" {
+        prefix: "DERMO",
+        name: "Human Dermatological Disease Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    dideo, DIDEO_PATTERN => "^DIDEO:\\d{8}$" {
+        prefix: "DIDEO",
+        name: "Drug-drug Interaction and Drug-drug Interaction Evidence Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/DIDEO_$1"),
+        license: None,
+    },
+    disdriv, DISDRIV_PATTERN => "^DISDRIV:\\d+$" {
+        prefix: "DISDRIV",
+        name: "Disease Drivers Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/DISDRIV_$1"),
+        license: None,
+    },
+    doid, DOID_PATTERN => "^DOID:\\d+$" {
+        prefix: "DOID",
+        name: "Human Disease Ontology",
+        synonyms: &["DO"],
+        description: Some("The Disease Ontology has been developed as a standardized ontology for human disease."),
+        homepage: Some("http://www.disease-ontology.org"),
+        example: Some("4"),
+        banana: Some("DOID:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/DOID_$1"),
+        license: Some("CC0-1.0"),
+    },
+    dron, DRON_PATTERN => "^DRON:\\d{8}$" {
+        prefix: "DRON",
+        name: "The Drug Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/DRON_$1"),
+        license: None,
+    },
+    drugcentral, DRUGCENTRAL_PATTERN => "^DrugCentral:\\d+$" {
+        prefix: "DrugCentral",
+        name: "Drug Central",
+        synonyms: &["Drug_Central"],
+        description: Some("DrugCentral is a comprehensive drug information resource for FDA drugs and drugs approved outside USA."),
+        homepage: Some("http://drugcentral.org"),
+        example: Some("307"),
+        banana: None,
+        uri_format: Some("https://drugcentral.org/drugcard/$1"),
+        license: Some("CC-BY-SA-4.0"),
+    },
+    dto, DTO_PATTERN => "^DTO:\\d+$" {
+        prefix: "DTO",
+        name: "Drug Target Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://www.drugtargetontology.org/dto/DTO_$1"),
+        license: None,
+    },
+    duo, DUO_PATTERN => "^DUO:\\d{7}$" {
+        prefix: "DUO",
+        name: "Data Use Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/DUO_$1"),
+        license: None,
+    },
+    ecao, ECAO_PATTERN => "^ECAO:\\d{7}$" {
+        prefix: "ECAO",
+        name: "The Echinoderm Anatomy and Development Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ECAO_$1"),
+        license: None,
+    },
+    ecg, ECG_PATTERN => "^ECG:\\d+$" {
+        prefix: "ECG",
+        name: "Electrocardiogram Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    eco, ECO_PATTERN => "^ECO:\\d{7}$" {
+        prefix: "ECO",
+        name: "Evidence and Conclusion Ontology",
+        synonyms: &[],
+        description: Some("The Evidence & Conclusion Ontology (ECO) describes types of scientific evidence within the biological research domain."),
+        homepage: Some("https://www.evidenceontology.org"),
+        example: Some("0000000"),
+        banana: Some("ECO:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/ECO_$1"),
+        license: Some("CC0-1.0"),
+    },
+    ecocore, ECOCORE_PATTERN => "^ECOCORE:\\d+$" {
+        prefix: "ECOCORE",
+        name: "An ontology of core ecological entities",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ECOCORE_$1"),
+        license: None,
+    },
+    ecso, ECSO_PATTERN => "^ECSO:\\d+$" {
+        prefix: "ECSO",
+        name: "The Ecosystem Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.dataone.org/odo/ECSO_$1"),
+        license: None,
+    },
+    ecto, ECTO_PATTERN => "^ECTO:\\d{7}$" {
+        prefix: "ECTO",
+        name: "Environmental conditions, treatments and exposures ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ECTO_$1"),
+        license: None,
+    },
+    ehda, EHDA_PATTERN => "^EHDA:\\d+$" {
+        prefix: "EHDA",
+        name: "Human developmental anatomy, timed version",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/EHDA_$1"),
+        license: None,
+    },
+    ehdaa, EHDAA_PATTERN => "^EHDAA:\\d+$" {
+        prefix: "EHDAA",
+        name: "Human developmental anatomy, abstract version",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/EHDAA_$1"),
+        license: None,
+    },
+    ehdaa2, EHDAA2_PATTERN => "^EHDAA2:\\d{7}$" {
+        prefix: "EHDAA2",
+        name: "Human developmental anatomy, abstract",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/EHDAA2_$1"),
+        license: None,
+    },
+    emap, EMAP_PATTERN => "^EMAP:\\d+$" {
+        prefix: "EMAP",
+        name: "Mouse gross anatomy and development, timed",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/EMAP_$1"),
+        license: None,
+    },
+    emapa, EMAPA_PATTERN => "^EMAPA:\\d+$" {
+        prefix: "EMAPA",
+        name: "Mouse Developmental Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/EMAPA_$1"),
+        license: None,
+    },
+    enm, ENM_PATTERN => "^ENM:\\d+$" {
+        prefix: "ENM",
+        name: "eNanoMapper ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.enanomapper.org/onto/ENM_$1"),
+        license: None,
+    },
+    envo, ENVO_PATTERN => "^ENVO:\\d{7,8}$" {
+        prefix: "ENVO",
+        name: "Environment Ontology",
+        synonyms: &[],
+        description: Some("The Environment Ontology is a resource and research target for the semantically controlled description of environmental entities."),
+        homepage: Some("http://environmentontology.org/"),
+        example: Some("01000254"),
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ENVO_$1"),
+        license: Some("CC0-1.0"),
+    },
+    eol, EOL_PATTERN => "^EOL:\\d{7}$" {
+        prefix: "EOL",
+        name: "Environment Ontology for Livestock",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/EOL_$1"),
+        license: None,
+    },
+    epio, EPIO_PATTERN => "^EPIO:\\d{7}$" {
+        prefix: "EPIO",
+        name: "Epilepsy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/EPIO_$1"),
+        license: None,
+    },
+    epso, EPSO_PATTERN => "^EPSO:\\d{7}$" {
+        prefix: "EPSO",
+        name: "Epilepsy and Seizure Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/EPSO_$1"),
+        license: None,
+    },
+    ero, ERO_PATTERN => "^ERO:\\d{7}$" {
+        prefix: "ERO",
+        name: "eagle-i resource ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ERO_$1"),
+        license: None,
+    },
+    eupath, EUPATH_PATTERN => "^EUPATH:\\d{7}$" {
+        prefix: "EUPATH",
+        name: "VEuPathDB ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/EUPATH_$1"),
+        license: None,
+    },
+    exo, EXO_PATTERN => "^ExO:\\d{7}$" {
+        prefix: "ExO",
+        name: "Exposure ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ExO_$1"),
+        license: None,
+    },
+    fao, FAO_PATTERN => "^FAO:\\d{7}$" {
+        prefix: "FAO",
+        name: "Fungal gross anatomy",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FAO_$1"),
+        license: None,
+    },
+    fbbi, FBBI_PATTERN => "^FBbi:\\d+$" {
+        prefix: "FBbi",
+        name: "Biological Imaging Methods Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FBbi_$1"),
+        license: None,
+    },
+    fbbt, FBBT_PATTERN => "^FBbt:\\d{8}$" {
+        prefix: "FBbt",
+        name: "Drosophila gross anatomy",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FBbt_$1"),
+        license: None,
+    },
+    fbcv, FBCV_PATTERN => "^FBcv:\\d{7}$" {
+        prefix: "FBcv",
+        name: "FlyBase Controlled Vocabulary",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FBcv_$1"),
+        license: None,
+    },
+    fbdv, FBDV_PATTERN => "^FBdv:\\d{8}$" {
+        prefix: "FBdv",
+        name: "Drosophila development",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FBdv_$1"),
+        license: None,
+    },
+    fideo, FIDEO_PATTERN => "^FIDEO:\\d+$" {
+        prefix: "FIDEO",
+        name: "Food Interactions with Drugs Evidence Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FIDEO_$1"),
+        license: None,
+    },
+    fix, FIX_PATTERN => "^FIX:\\d{7}$" {
+        prefix: "FIX",
+        name: "Physico-chemical methods and properties",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FIX_$1"),
+        license: None,
+    },
+    flopo, FLOPO_PATTERN => "^FLOPO:\\d{7}$" {
+        prefix: "FLOPO",
+        name: "Flora Phenotype Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FLOPO_$1"),
+        license: None,
+    },
+    flu, FLU_PATTERN => "^FLU:\\d{7}$" {
+        prefix: "FLU",
+        name: "Influenza Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FLU_$1"),
+        license: None,
+    },
+    fma, FMA_PATTERN => "^FMA:\\d+$" {
+        prefix: "FMA",
+        name: "Foundational Model of Anatomy",
+        synonyms: &["FMAID"],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: Some("FMA:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/FMA_$1"),
+        license: None,
+    },
+    fobi, FOBI_PATTERN => "^FOBI:\\d{4,6}$" {
+        prefix: "FOBI",
+        name: "Food-Biomarker Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FOBI_$1"),
+        license: None,
+    },
+    foodon, FOODON_PATTERN => "^FOODON:[0-9]{8}$" {
+        prefix: "FOODON",
+        name: "The Food Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FOODON_$1"),
+        license: None,
+    },
+    fovt, FOVT_PATTERN => "^FOVT:\\d{7}$" {
+        prefix: "FOVT",
+        name: "FuTRES Ontology of Vertebrate Traits",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FOVT_$1"),
+        license: None,
+    },
+    fypo, FYPO_PATTERN => "^FYPO:\\d{7}$" {
+        prefix: "FYPO",
+        name: "Fission Yeast Phenotype Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/FYPO_$1"),
+        license: None,
+    },
+    gallont, GALLONT_PATTERN => "^GALLONT:\\d{7}$" {
+        prefix: "GALLONT",
+        name: "Gallus Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/GALLONT_$1"),
+        license: None,
+    },
+    gaz, GAZ_PATTERN => "^GAZ:\\d{8}$" {
+        prefix: "GAZ",
+        name: "Gazetteer",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/GAZ_$1"),
+        license: None,
+    },
+    gecko, GECKO_PATTERN => "^GECKO:\\d{7}$" {
+        prefix: "GECKO",
+        name: "Genomics Cohorts Knowledge Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/GECKO_$1"),
+        license: None,
+    },
+    genepio, GENEPIO_PATTERN => "^GENEPIO:\\d{7}$" {
+        prefix: "GENEPIO",
+        name: "Genomic Epidemiology Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/GENEPIO_$1"),
+        license: None,
+    },
+    geno, GENO_PATTERN => "^GENO:\\d{7}$" {
+        prefix: "GENO",
+        name: "Genotype Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/GENO_$1"),
+        license: None,
+    },
+    geo, GEO_PATTERN => "^GEO:\\d{9}$" {
+        prefix: "GEO",
+        name: "Geographical Entity Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/GEO_$1"),
+        license: None,
+    },
+    gno, GNO_PATTERN => "^GNO:(\\d{8}|(\\w+\\d+\\w+))$" {
+        prefix: "GNO",
+        name: "Glycan Naming and Subsumption Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/GNO_$1"),
+        license: None,
+    },
+    go, GO_PATTERN => "^GO:\\d{7}$" {
+        prefix: "GO",
+        name: "Gene Ontology",
+        synonyms: &["gobp", "gocc", "gomf"],
+        description: Some("The Gene Ontology project provides a controlled vocabulary to describe gene and gene product attributes in any organism."),
+        homepage: Some("http://geneontology.org/"),
+        example: Some("0008150"),
+        banana: Some("GO:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/GO_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    gpml, GPML_PATTERN => "^GPML:[A-Za-z]+$" {
+        prefix: "GPML",
+        name: "WikiPathways GPML Vocabulary",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://vocabularies.wikipathways.org/gpml#$1"),
+        license: None,
+    },
+    gsso, GSSO_PATTERN => "^GSSO:\\d{6}$" {
+        prefix: "GSSO",
+        name: "Gender, Sex, and Sexual Orientation Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/GSSO_$1"),
+        license: None,
+    },
+    hancestro, HANCESTRO_PATTERN => "^HANCESTRO:\\d{4}$" {
+        prefix: "HANCESTRO",
+        name: "Human Ancestry Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/HANCESTRO_$1"),
+        license: None,
+    },
+    hao, HAO_PATTERN => "^HAO:\\d{7}$" {
+        prefix: "HAO",
+        name: "Hymenoptera Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/HAO_$1"),
+        license: None,
+    },
+    hgnc, HGNC_PATTERN => "^HGNC:\\d{1,5}$" {
+        prefix: "HGNC",
+        name: "HUGO Gene Nomenclature Committee",
+        synonyms: &[],
+        description: Some("The HGNC (HUGO Gene Nomenclature Committee) provides an approved gene name and symbol for each known human gene."),
+        homepage: Some("https://www.genenames.org"),
+        example: Some("5"),
+        banana: None,
+        uri_format: Some("https://www.genenames.org/cgi-bin/gene_symbol_report?hgnc_id=$1"),
+        license: Some("CC0-1.0"),
+    },
+    hoip, HOIP_PATTERN => "^HOIP:\\d{7}$" {
+        prefix: "HOIP",
+        name: "Homeostasis imbalance process ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/HOIP_$1"),
+        license: None,
+    },
+    hom, HOM_PATTERN => "^HOM:\\d{7}$" {
+        prefix: "HOM",
+        name: "Homology Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/HOM_$1"),
+        license: None,
+    },
+    hp, HP_PATTERN => "^HP:\\d{7}$" {
+        prefix: "HP",
+        name: "Human Phenotype Ontology",
+        synonyms: &["HPO", "HP_O", "human_phenotype_ontology"],
+        description: Some("The Human Phenotype Ontology (HPO) provides a standardized vocabulary of phenotypic abnormalities encountered in human disease."),
+        homepage: Some("https://hpo.jax.org"),
+        example: Some("0000118"),
+        banana: Some("HP:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/HP_$1"),
+        license: None,
+    },
+    hsapdv, HSAPDV_PATTERN => "^HsapDv:\\d{7}$" {
+        prefix: "HsapDv",
+        name: "Human Developmental Stages",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/HsapDv_$1"),
+        license: None,
+    },
+    hso, HSO_PATTERN => "^HSO:\\d{7}$" {
+        prefix: "HSO",
+        name: "Health Surveillance Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/HSO_$1"),
+        license: None,
+    },
+    htn, HTN_PATTERN => "^HTN:\\d{8}$" {
+        prefix: "HTN",
+        name: "Hypertension Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/HTN_$1"),
+        license: None,
+    },
+    icd10, ICD10_PATTERN => "^ICD10:(([XVI]+)|([A-Z][0-9]+((-[A-Z][0-9]+)|(\\.[0-9]))?))$" {
+        prefix: "ICD10",
+        name: "International Classification of Diseases, 10th Revision",
+        synonyms: &["ICD10WHO", "ICD-10"],
+        description: None,
+        homepage: Some("https://icd.who.int/browse10"),
+        example: Some("C34"),
+        banana: None,
+        uri_format: Some("https://icd.who.int/browse10/2019/en#/$1"),
+        license: None,
+    },
+    icd10cm, ICD10CM_PATTERN => "^ICD10CM:([A-Z][0-9][0-9AB]((-[A-Z][0-9][0-9AB])|(\\.[0-9A-KXZ]([0-9A-EXYZ]([0-9A-HX][0-59A-HJKMNP-S]?)?)?)?))$" {
+        prefix: "ICD10CM",
+        name: "International Classification of Diseases, 10th Revision, Clinical Modification",
+        synonyms: &["ICD-10-CM"],
+        description: None,
+        homepage: Some("https://www.icd10data.com/ICD10CM/Codes"),
+        example: Some("F45.21"),
+        banana: None,
+        uri_format: Some("https://icd.codes/icd10cm/$1"),
+        license: None,
+    },
+    icd9cm, ICD9CM_PATTERN => "^ICD9CM:(?:([\\dA-Z]\\d{2}(\\.\\d{1,3}|))|(\\d{2}(\\.\\d{1,2}|)))$" {
+        prefix: "ICD9CM",
+        name: "International Classification of Diseases, 9th Revision, Clinical Modification",
+        synonyms: &["ICD-9-CM"],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.bioontology.org/ontology/ICD9CM/$1"),
+        license: None,
+    },
+    iceo, ICEO_PATTERN => "^ICEO:\\d{7}(_\\d)?$" {
+        prefix: "ICEO",
+        name: "Integrative and Conjugative Element Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ICEO_$1"),
+        license: None,
+    },
+    icf, ICF_PATTERN => "^ICF:\\d+$" {
+        prefix: "ICF",
+        name: "International Classification of Functioning, Disability and Health",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    ico, ICO_PATTERN => "^ICO:\\d{7}$" {
+        prefix: "ICO",
+        name: "Informed Consent Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ICO_$1"),
+        license: None,
+    },
+    ido, IDO_PATTERN => "^IDO:\\d{7}$" {
+        prefix: "IDO",
+        name: "Infectious Disease Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/IDO_$1"),
+        license: None,
+    },
+    idoden, IDODEN_PATTERN => "^IDODEN:\\d{7}$" {
+        prefix: "IDODEN",
+        name: "Dengue Fever Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/IDODEN_$1"),
+        license: None,
+    },
+    idomal, IDOMAL_PATTERN => "^IDOMAL:(5?)\\d{7}$" {
+        prefix: "IDOMAL",
+        name: "Malaria Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/IDOMAL_$1"),
+        license: None,
+    },
+    ino, INO_PATTERN => "^INO:\\d{7}$" {
+        prefix: "INO",
+        name: "Interaction Network Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/INO_$1"),
+        license: None,
+    },
+    ito, ITO_PATTERN => "^ITO:\\d+$" {
+        prefix: "ITO",
+        name: "Intelligence Task Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    kisao, KISAO_PATTERN => "^KISAO:\\d+$" {
+        prefix: "KISAO",
+        name: "Kinetic Simulation Algorithm Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://www.biomodels.net/kisao/KISAO#KISAO_$1"),
+        license: None,
+    },
+    labo, LABO_PATTERN => "^LABO:\\d{7}$" {
+        prefix: "LABO",
+        name: "clinical LABoratory Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/LABO_$1"),
+        license: None,
+    },
+    lbo, LBO_PATTERN => "^LBO:\\d{7}$" {
+        prefix: "LBO",
+        name: "Livestock Breed Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/LBO_$1"),
+        license: None,
+    },
+    lepao, LEPAO_PATTERN => "^LEPAO:\\d{7}$" {
+        prefix: "LEPAO",
+        name: "Lepidoptera Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/LEPAO_$1"),
+        license: None,
+    },
+    loinc, LOINC_PATTERN => "^LOINC:(\\d|\\w)+-\\d$" {
+        prefix: "LOINC",
+        name: "Logical Observation Identifiers Names and Codes",
+        synonyms: &["LNC"],
+        description: Some("LOINC is a common language for identifying health measurements, observations, and documents."),
+        homepage: Some("https://loinc.org/"),
+        example: Some("26464-8"),
+        banana: None,
+        uri_format: Some("https://loinc.org/$1"),
+        license: None,
+    },
+    lpt, LPT_PATTERN => "^LPT:\\d+$" {
+        prefix: "LPT",
+        name: "Livestock Product Trait Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    ma, MA_PATTERN => "^MA:\\d+$" {
+        prefix: "MA",
+        name: "Mouse adult gross anatomy",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: Some("MA:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/MA_$1"),
+        license: None,
+    },
+    mamo, MAMO_PATTERN => "^MAMO:\\d{7}$" {
+        prefix: "MAMO",
+        name: "Mathematical modeling ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MAMO_$1"),
+        license: None,
+    },
+    mat, MAT_PATTERN => "^MAT:\\d{7}$" {
+        prefix: "MAT",
+        name: "Minimal Anatomical Terminology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MAT_$1"),
+        license: None,
+    },
+    maxo, MAXO_PATTERN => "^MAXO:\\d{7}$" {
+        prefix: "MAXO",
+        name: "Medical Action Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MAXO_$1"),
+        license: None,
+    },
+    mco, MCO_PATTERN => "^MCO:\\d+$" {
+        prefix: "MCO",
+        name: "Microbial Conditions Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MCO_$1"),
+        license: None,
+    },
+    mcro, MCRO_PATTERN => "^MCRO:\\d{7}$" {
+        prefix: "MCRO",
+        name: "Model Card Report Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MCRO_$1"),
+        license: None,
+    },
+    mesh, MESH_PATTERN => "^MESH:(C|D|Q)\\d+$" {
+        prefix: "MESH",
+        name: "Medical Subject Headings",
+        synonyms: &["MSH", "mesh.2012", "mesh.2013"],
+        description: Some("MeSH (Medical Subject Headings) is the National Library of Medicine's controlled vocabulary thesaurus."),
+        homepage: Some("https://www.nlm.nih.gov/mesh/"),
+        example: Some("D009369"),
+        banana: None,
+        uri_format: Some("http://id.nlm.nih.gov/mesh/$1"),
+        license: None,
+    },
+    metpo, METPO_PATTERN => "^METPO:\\d+$" {
+        prefix: "METPO",
+        name: "Microbial Ecophysiological Trait and Phenotype Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    mf, MF_PATTERN => "^MF:\\d{7}$" {
+        prefix: "MF",
+        name: "Mental Functioning Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MF_$1"),
+        license: None,
+    },
+    mfmo, MFMO_PATTERN => "^MFMO:\\d{7}$" {
+        prefix: "MFMO",
+        name: "Mammalian Feeding Muscle Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MFMO_$1"),
+        license: None,
+    },
+    mfo, MFO_PATTERN => "^MFO:\\d{7}$" {
+        prefix: "MFO",
+        name: "Medaka Fish Anatomy and Development",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MFO_$1"),
+        license: None,
+    },
+    mfoem, MFOEM_PATTERN => "^MFOEM:\\d{6}$" {
+        prefix: "MFOEM",
+        name: "Emotion Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MFOEM_$1"),
+        license: None,
+    },
+    mfomd, MFOMD_PATTERN => "^MFOMD:\\d{7}$" {
+        prefix: "MFOMD",
+        name: "Mental Disease Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MFOMD_$1"),
+        license: None,
+    },
+    mi, MI_PATTERN => "^MI:\\d{4}$" {
+        prefix: "MI",
+        name: "Molecular Interactions Controlled Vocabulary",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MI_$1"),
+        license: None,
+    },
+    miapa, MIAPA_PATTERN => "^MIAPA:\\d{7}$" {
+        prefix: "MIAPA",
+        name: "Minimum Anformation About a Phylogenetic Analysis Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MIAPA_$1"),
+        license: None,
+    },
+    micro, MICRO_PATTERN => "^MICRO:\\d{7}$" {
+        prefix: "MICRO",
+        name: "Ontology of Prokaryotic Phenotypic and Metabolic Characters",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MICRO_$1"),
+        license: None,
+    },
+    miro, MIRO_PATTERN => "^MIRO:\\d{8}$" {
+        prefix: "MIRO",
+        name: "Mosquito insecticide resistance",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MIRO_$1"),
+        license: None,
+    },
+    mixs, MIXS_PATTERN => "^MIXS:\\d{7}$" {
+        prefix: "MIXS",
+        name: "Minimum Information about any (x) Sequence",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    mmo, MMO_PATTERN => "^MMO:\\d{7}$" {
+        prefix: "MMO",
+        name: "Measurement method ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MMO_$1"),
+        license: None,
+    },
+    mmusdv, MMUSDV_PATTERN => "^MmusDv:\\d{7}$" {
+        prefix: "MmusDv",
+        name: "Mouse Developmental Stages",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MmusDv_$1"),
+        license: None,
+    },
+    mondo, MONDO_PATTERN => "^MONDO:\\d{7}$" {
+        prefix: "MONDO",
+        name: "Mondo Disease Ontology",
+        synonyms: &[],
+        description: Some("A global community effort to harmonize multiple disease resources to yield a coherent merged ontology."),
+        homepage: Some("https://monarch-initiative.github.io/mondo"),
+        example: Some("0000001"),
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MONDO_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    mop, MOP_PATTERN => "^MOP:\\d{7}$" {
+        prefix: "MOP",
+        name: "Molecular Process Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MOP_$1"),
+        license: None,
+    },
+    mosaic, MOSAIC_PATTERN => "^MOSAIC:\\d{8}$" {
+        prefix: "MOSAIC",
+        name: "MOSAiC Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("https://purl.dataone.org/odo/MOSAIC_$1"),
+        license: None,
+    },
+    mp, MP_PATTERN => "^MP:\\d{7}$" {
+        prefix: "MP",
+        name: "Mammalian Phenotype Ontology",
+        synonyms: &[],
+        description: Some("The Mammalian Phenotype Ontology is a standard terminology for annotating mammalian phenotypic data."),
+        homepage: Some("http://www.informatics.jax.org/vocab/mp_ontology"),
+        example: Some("0000001"),
+        banana: Some("MP:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/MP_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    mpath, MPATH_PATTERN => "^MPATH:\\d+$" {
+        prefix: "MPATH",
+        name: "Mouse pathology ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MPATH_$1"),
+        license: None,
+    },
+    mpio, MPIO_PATTERN => "^MPIO:\\d{7}$" {
+        prefix: "MPIO",
+        name: "Minimum PDDI Information Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MPIO_$1"),
+        license: None,
+    },
+    mro, MRO_PATTERN => "^MRO:\\d{7}$" {
+        prefix: "MRO",
+        name: "MHC Restriction Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MRO_$1"),
+        license: None,
+    },
+    ms, MS_PATTERN => "^MS:\\d{7}$" {
+        prefix: "MS",
+        name: "Mass spectrometry ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/MS_$1"),
+        license: None,
+    },
+    mwo, MWO_PATTERN => "^MWO:\\d{7}$" {
+        prefix: "MWO",
+        name: "NFDI MatWerk Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    nando, NANDO_PATTERN => "^NANDO:\\d+$" {
+        prefix: "NANDO",
+        name: "Nanbyo Disease Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://nanbyodata.jp/ontology/NANDO_$1"),
+        license: None,
+    },
+    nbo, NBO_PATTERN => "^NBO:\\d{7}$" {
+        prefix: "NBO",
+        name: "Neuro Behavior Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/NBO_$1"),
+        license: None,
+    },
+    ncbitaxon, NCBITAXON_PATTERN => "^NCBITaxon:(?:(\\d+)|([a-zA-Z_]+))$" {
+        prefix: "NCBITaxon",
+        name: "NCBI Taxonomy",
+        synonyms: &["NCBI_Taxon", "NCBI_TaxID", "ncbi.taxon", "taxid", "taxon", "uniprot.taxonomy"],
+        description: Some("The NCBI Taxonomy is a curated classification and nomenclature for all of the organisms in the public sequence databases."),
+        homepage: Some("https://www.ncbi.nlm.nih.gov/taxonomy"),
+        example: Some("9606"),
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/NCBITaxon_$1"),
+        license: Some("CC0-1.0"),
+    },
+    ncit, NCIT_PATTERN => "^NCIT:[CRPA]\\d+$" {
+        prefix: "NCIT",
+        name: "NCI Thesaurus",
+        synonyms: &["NCI", "NCI_Thesaurus", "ncithesaurus"],
+        description: Some("NCI Thesaurus (NCIt) is a reference terminology that includes broad coverage of the cancer domain."),
+        homepage: Some("https://ncit.nci.nih.gov/"),
+        example: Some("C3224"),
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/NCIT_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    ncro, NCRO_PATTERN => "^NCRO:\\d{7}$" {
+        prefix: "NCRO",
+        name: "Non-Coding RNA Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/NCRO_$1"),
+        license: None,
+    },
+    nddf, NDDF_PATTERN => "^NDDF:\\d{6}$" {
+        prefix: "NDDF",
+        name: "National Drug Data File",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.bioontology.org/ontology/NDDF/$1"),
+        license: None,
+    },
+    ngbo, NGBO_PATTERN => "^NGBO:\\d{7}$" {
+        prefix: "NGBO",
+        name: "Next Generation Biobanking Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/NGBO_$1"),
+        license: None,
+    },
+    nifstd, NIFSTD_PATTERN => "^NIFSTD:BAMSC\\d+$" {
+        prefix: "NIFSTD",
+        name: "NIF Standard Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://uri.neuinfo.org/nif/nifstd/$1"),
+        license: None,
+    },
+    nmr, NMR_PATTERN => "^NMR:\\d+$" {
+        prefix: "NMR",
+        name: "NMR-instrument specific component of metabolomics investigations",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://nmrML.org/nmrCV#NMR:$1"),
+        license: None,
+    },
+    nomen, NOMEN_PATTERN => "^NOMEN:\\d{7}$" {
+        prefix: "NOMEN",
+        name: "A nomenclatural ontology for biological names",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/NOMEN_$1"),
+        license: None,
+    },
+    npo, NPO_PATTERN => "^NPO:\\d+$" {
+        prefix: "NPO",
+        name: "NanoParticle Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.bioontology.org/ontology/npo#NPO_$1"),
+        license: None,
+    },
+    oae, OAE_PATTERN => "^OAE:\\d{7}$" {
+        prefix: "OAE",
+        name: "Ontology of Adverse Events",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OAE_$1"),
+        license: None,
+    },
+    oarcs, OARCS_PATTERN => "^OARCS:\\d{7}$" {
+        prefix: "OARCS",
+        name: "Ontology of Arthropod Circulatory Systems",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OARCS_$1"),
+        license: None,
+    },
+    oba, OBA_PATTERN => "^OBA:(VT)?\\d{7}$" {
+        prefix: "OBA",
+        name: "Ontology of Biological Attributes",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OBA_$1"),
+        license: None,
+    },
+    obcs, OBCS_PATTERN => "^OBCS:\\d{7}$" {
+        prefix: "OBCS",
+        name: "Ontology of Biological and Clinical Statistics",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OBCS_$1"),
+        license: None,
+    },
+    obi, OBI_PATTERN => "^OBI:\\d{7}$" {
+        prefix: "OBI",
+        name: "Ontology for Biomedical Investigations",
+        synonyms: &[],
+        description: Some("The Ontology for Biomedical Investigations (OBI) helps to communicate the details of biological and medical investigations."),
+        homepage: Some("http://obi-ontology.org"),
+        example: Some("0000070"),
+        banana: Some("OBI:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/OBI_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    obib, OBIB_PATTERN => "^OBIB:\\d{7}$" {
+        prefix: "OBIB",
+        name: "Ontology for Biobanking",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OBIB_$1"),
+        license: None,
+    },
+    occo, OCCO_PATTERN => "^OCCO:\\d+$" {
+        prefix: "OCCO",
+        name: "Occupation Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OCCO_$1"),
+        license: None,
+    },
+    ogg, OGG_PATTERN => "^OGG:\\d+$" {
+        prefix: "OGG",
+        name: "The Ontology of Genes and Genomes",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OGG_$1"),
+        license: None,
+    },
+    ogi, OGI_PATTERN => "^OGI:\\d{7}$" {
+        prefix: "OGI",
+        name: "Ontology for genetic interval",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OGI_$1"),
+        license: None,
+    },
+    ogms, OGMS_PATTERN => "^OGMS:\\d{7}$" {
+        prefix: "OGMS",
+        name: "Ontology for General Medical Science",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OGMS_$1"),
+        license: None,
+    },
+    ogsf, OGSF_PATTERN => "^OGSF:\\d{7}$" {
+        prefix: "OGSF",
+        name: "Ontology of Genetic Susceptibility Factor",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OGSF_$1"),
+        license: None,
+    },
+    ohd, OHD_PATTERN => "^OHD:\\d{7}$" {
+        prefix: "OHD",
+        name: "Oral Health and Disease Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OHD_$1"),
+        license: None,
+    },
+    ohmi, OHMI_PATTERN => "^OHMI:\\d{7}$" {
+        prefix: "OHMI",
+        name: "Ontology of Host-Microbiome Interactions",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OHMI_$1"),
+        license: None,
+    },
+    ohpi, OHPI_PATTERN => "^OHPI:\\d+$" {
+        prefix: "OHPI",
+        name: "Ontology of Host Pathogen Interactions",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OHPI_$1"),
+        license: None,
+    },
+    olatdv, OLATDV_PATTERN => "^OlatDv:\\d{7}$" {
+        prefix: "OlatDv",
+        name: "Medaka Developmental Stages",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OlatDv_$1"),
+        license: None,
+    },
+    omiabis, OMIABIS_PATTERN => "^OMIABIS:\\d{7}$" {
+        prefix: "OMIABIS",
+        name: "Ontologized MIABIS",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OMIABIS_$1"),
+        license: None,
+    },
+    omim, OMIM_PATTERN => "^OMIM:\\d+$" {
+        prefix: "OMIM",
+        name: "Online Mendelian Inheritance in Man",
+        synonyms: &["MIM"],
+        description: Some("Online Mendelian Inheritance in Man is a catalog of human genes and genetic disorders."),
+        homepage: Some("https://omim.org/"),
+        example: Some("603903"),
+        banana: None,
+        uri_format: Some("https://omim.org/entry/$1"),
+        license: None,
+    },
+    omo, OMO_PATTERN => "^OMO:\\d{7}$" {
+        prefix: "OMO",
+        name: "OBO Metadata Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OMO_$1"),
+        license: None,
+    },
+    omp, OMP_PATTERN => "^OMP:\\d{7}$" {
+        prefix: "OMP",
+        name: "Ontology of Microbial Phenotypes",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OMP_$1"),
+        license: None,
+    },
+    omrse, OMRSE_PATTERN => "^OMRSE:\\d{8}$" {
+        prefix: "OMRSE",
+        name: "Ontology of Medically Related Social Entities",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OMRSE_$1"),
+        license: None,
+    },
+    one, ONE_PATTERN => "^ONE:\\d{7}$" {
+        prefix: "ONE",
+        name: "Ontology for Nutritional Epidemiology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ONE_$1"),
+        license: None,
+    },
+    ons, ONS_PATTERN => "^ONS:\\d{7}$" {
+        prefix: "ONS",
+        name: "Ontology for Nutritional Studies",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ONS_$1"),
+        license: None,
+    },
+    ontoavida, ONTOAVIDA_PATTERN => "^ONTOAVIDA:\\d{8}$" {
+        prefix: "ONTOAVIDA",
+        name: "Ontology for Avida digital evolution platform",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ONTOAVIDA_$1"),
+        license: None,
+    },
+    ontoneo, ONTONEO_PATTERN => "^ONTONEO:\\d{8}$" {
+        prefix: "ONTONEO",
+        name: "Obstetric and Neonatal Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ONTONEO_$1"),
+        license: None,
+    },
+    oostt, OOSTT_PATTERN => "^OOSTT:\\d{8}$" {
+        prefix: "OOSTT",
+        name: "Ontology of Organizational Structures of Trauma centers and Trauma systems",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OOSTT_$1"),
+        license: None,
+    },
+    opb, OPB_PATTERN => "^OPB:\\d+$" {
+        prefix: "OPB",
+        name: "Ontology of Physics for Biology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://bhi.washington.edu/OPB#OPB_$1"),
+        license: None,
+    },
+    opl, OPL_PATTERN => "^OPL:\\d{7}$" {
+        prefix: "OPL",
+        name: "Ontology for Parasite LifeCycle",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OPL_$1"),
+        license: None,
+    },
+    opmi, OPMI_PATTERN => "^OPMI:\\d{7}$" {
+        prefix: "OPMI",
+        name: "Ontology of Precision Medicine and Investigation",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OPMI_$1"),
+        license: None,
+    },
+    ornaseq, ORNASEQ_PATTERN => "^ORNASEQ:\\d{7}$" {
+        prefix: "ORNASEQ",
+        name: "Ontology of RNA Sequencing",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ORNASEQ_$1"),
+        license: None,
+    },
+    ordo, ORDO_PATTERN => "^ORDO:C?\\d+$" {
+        prefix: "ORDO",
+        name: "Orphanet Rare Disease Ontology",
+        synonyms: &["Orphanet"],
+        description: Some("The Orphanet Rare Disease ontology (ORDO) is a structured vocabulary for rare diseases, capturing relationships between diseases, genes and other relevant features."),
+        homepage: Some("https://www.orphadata.com/ordo/"),
+        example: Some("558"),
+        banana: None,
+        uri_format: Some("http://www.orpha.net/ORDO/Orphanet_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    ovae, OVAE_PATTERN => "^OVAE:\\d{7}$" {
+        prefix: "OVAE",
+        name: "Ontology of Vaccine Adverse Events",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/OVAE_$1"),
+        license: None,
+    },
+    pato, PATO_PATTERN => "^PATO:\\d{7}$" {
+        prefix: "PATO",
+        name: "Phenotype And Trait Ontology",
+        synonyms: &[],
+        description: Some("PATO is an ontology of phenotypic qualities, intended for use in a number of applications, primarily defining composite phenotypes and phenotype annotation."),
+        homepage: Some("https://github.com/pato-ontology/pato"),
+        example: Some("0000001"),
+        banana: Some("PATO:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/PATO_$1"),
+        license: Some("CC-BY-3.0"),
+    },
+    pav, PAV_PATTERN => "^PAV:[a-z][a-zA-Z]+$" {
+        prefix: "PAV",
+        name: "Provenance, Authoring, and Versioning Vocabulary",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.org/pav/$1"),
+        license: None,
+    },
+    pcl, PCL_PATTERN => "^PCL:\\d{7}$" {
+        prefix: "PCL",
+        name: "Provisional Cell Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PCL_$1"),
+        license: None,
+    },
+    pco, PCO_PATTERN => "^PCO:\\d{7}$" {
+        prefix: "PCO",
+        name: "Population and Community Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PCO_$1"),
+        license: None,
+    },
+    pdro, PDRO_PATTERN => "^PDRO:\\d{7}$" {
+        prefix: "PDRO",
+        name: "The Prescription of Drugs Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PDRO_$1"),
+        license: None,
+    },
+    pdumdv, PDUMDV_PATTERN => "^PdumDv:\\d{7}$" {
+        prefix: "PdumDv",
+        name: "Platynereis Developmental Stages",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PdumDv_$1"),
+        license: None,
+    },
+    peco, PECO_PATTERN => "^PECO:\\d{7}$" {
+        prefix: "PECO",
+        name: "Plant Experimental Conditions Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PECO_$1"),
+        license: None,
+    },
+    phenx, PHENX_PATTERN => "^PHENX:\\d+$" {
+        prefix: "PHENX",
+        name: "PhenX Toolkit",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    phipo, PHIPO_PATTERN => "^PHIPO:\\d{7}$" {
+        prefix: "PHIPO",
+        name: "Pathogen Host Interaction Phenotype Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PHIPO_$1"),
+        license: None,
+    },
+    plana, PLANA_PATTERN => "^PLANA:\\d{7}$" {
+        prefix: "PLANA",
+        name: "Planarian Anatomy and Schmidtea mediterranean Developmental Stage Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PLANA_$1"),
+        license: None,
+    },
+    planp, PLANP_PATTERN => "^PLANP:\\d+$" {
+        prefix: "PLANP",
+        name: "Planarian Phenotype Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PLANP_$1"),
+        license: None,
+    },
+    plosthes, PLOSTHES_PATTERN => "^PLOSTHES:\\d+$" {
+        prefix: "PLOSTHES",
+        name: "PLOS Thesaurus",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    po, PO_PATTERN => "^PO:\\d+$" {
+        prefix: "PO",
+        name: "Plant Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PO_$1"),
+        license: None,
+    },
+    poro, PORO_PATTERN => "^PORO:\\d{7}$" {
+        prefix: "PORO",
+        name: "Porifera Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PORO_$1"),
+        license: None,
+    },
+    ppo, PPO_PATTERN => "^PPO:\\d{7}$" {
+        prefix: "PPO",
+        name: "Plant Phenology Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PPO_$1"),
+        license: None,
+    },
+    pr, PR_PATTERN => "^PR:(?:\\d{9}|[OPQ][0-9][A-Z0-9]{3}[0-9](?:-\\d+)?|[A-NR-Z][0-9](?:[A-Z][A-Z0-9]{2}[0-9]){1,2}(?:-\\d+)?)$" {
+        prefix: "PR",
+        name: "Protein Ontology",
+        synonyms: &["PRO"],
+        description: Some("The Protein Ontology (PRO) provides an ontological representation of protein-related entities."),
+        homepage: Some("https://proconsortium.org"),
+        example: Some("000000001"),
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PR_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    proco, PROCO_PATTERN => "^PROCO:\\d{7}$" {
+        prefix: "PROCO",
+        name: "Process Chemistry Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PROCO_$1"),
+        license: None,
+    },
+    psdo, PSDO_PATTERN => "^PSDO:\\d{7}$" {
+        prefix: "PSDO",
+        name: "Performance Summary Display Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PSDO_$1"),
+        license: None,
+    },
+    pso, PSO_PATTERN => "^PSO:\\d{7}$" {
+        prefix: "PSO",
+        name: "Plant Stress Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PSO_$1"),
+        license: None,
+    },
+    pw, PW_PATTERN => "^PW:\\d{7}$" {
+        prefix: "PW",
+        name: "Pathway ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/PW_$1"),
+        license: None,
+    },
+    radlex, RADLEX_PATTERN => "^RADLEX:RID\\d+$" {
+        prefix: "RADLEX",
+        name: "RSNA Informatics RadLex",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://radlex.org/RID/$1"),
+        license: None,
+    },
+    rbo, RBO_PATTERN => "^RBO:\\d{6,8}$" {
+        prefix: "RBO",
+        name: "Radiation Biology Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/RBO_$1"),
+        license: None,
+    },
+    reo, REO_PATTERN => "^REO:\\d{7}$" {
+        prefix: "REO",
+        name: "Reagent Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/REO_$1"),
+        license: None,
+    },
+    rex, REX_PATTERN => "^REX:\\d{7}$" {
+        prefix: "REX",
+        name: "Physico-chemical process",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/REX_$1"),
+        license: None,
+    },
+    rnao, RNAO_PATTERN => "^RNAO:\\d{7}$" {
+        prefix: "RNAO",
+        name: "RNA ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/RNAO_$1"),
+        license: None,
+    },
+    ro, RO_PATTERN => "^RO:(HOM)?\\d{7}$" {
+        prefix: "RO",
+        name: "Relation Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/RO_$1"),
+        license: None,
+    },
+    roleo, ROLEO_PATTERN => "^ROLEO:\\d{7}$" {
+        prefix: "ROLEO",
+        name: "Role Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ROLEO_$1"),
+        license: None,
+    },
+    rs, RS_PATTERN => "^RS:\\d{7}$" {
+        prefix: "RS",
+        name: "Rat Strain Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/RS_$1"),
+        license: None,
+    },
+    rxno, RXNO_PATTERN => "^RXNO:\\d{7}$" {
+        prefix: "RXNO",
+        name: "Name Reaction Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/RXNO_$1"),
+        license: None,
+    },
+    rxnorm, RXNORM_PATTERN => "^RXNORM:[0-9]{1,7}$" {
+        prefix: "RXNORM",
+        name: "RxNorm",
+        synonyms: &["RXCUI"],
+        description: Some("RxNorm provides normalized names for clinical drugs and links its names to many of the drug vocabularies commonly used in pharmacy management and drug interaction software."),
+        homepage: Some("https://www.nlm.nih.gov/research/umls/rxnorm/"),
+        example: Some("221058"),
+        banana: None,
+        uri_format: Some("http://purl.bioontology.org/ontology/RXNORM/$1"),
+        license: None,
+    },
+    salmon, SALMON_PATTERN => "^SALMON:\\d+$" {
+        prefix: "SALMON",
+        name: "Salmon Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("https://purl.dataone.org/odo/SALMON_$1"),
+        license: None,
+    },
+    sao, SAO_PATTERN => "^SAO:\\d+$" {
+        prefix: "SAO",
+        name: "Subcellular Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://uri.neuinfo.org/nif/nifstd/sao$1"),
+        license: None,
+    },
+    sasap, SASAP_PATTERN => "^SASAP:\\d+$" {
+        prefix: "SASAP",
+        name: "State of Alaska's Salmon and People Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.dataone.org/odo/SASAP_$1"),
+        license: None,
+    },
+    sbo, SBO_PATTERN => "^SBO:\\d{7}$" {
+        prefix: "SBO",
+        name: "Systems Biology Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/SBO_$1"),
+        license: None,
+    },
+    scdo, SCDO_PATTERN => "^SCDO:\\d{7}$" {
+        prefix: "SCDO",
+        name: "Sickle Cell Disease Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/SCDO_$1"),
+        license: None,
+    },
+    sdgio, SDGIO_PATTERN => "^SDGIO:\\d{8}$" {
+        prefix: "SDGIO",
+        name: "Sustainable Development Goals Interface Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/SDGIO_$1"),
+        license: None,
+    },
+    senso, SENSO_PATTERN => "^SENSO:\\d+$" {
+        prefix: "SENSO",
+        name: "Sensory Evaluation Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    sep, SEP_PATTERN => "^SEP:\\d{5,6}$" {
+        prefix: "SEP",
+        name: "Sample processing and separation techniques",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/SEP_$1"),
+        license: None,
+    },
+    sepio, SEPIO_PATTERN => "^SEPIO:\\d{7}$" {
+        prefix: "SEPIO",
+        name: "Scientific Evidence and Provenance Information Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/SEPIO_$1"),
+        license: None,
+    },
+    sibo, SIBO_PATTERN => "^SIBO:\\d{7}$" {
+        prefix: "SIBO",
+        name: "Social Insect Behavior Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/SIBO_$1"),
+        license: None,
+    },
+    sio, SIO_PATTERN => "^SIO:\\d{6}$" {
+        prefix: "SIO",
+        name: "Semanticscience Integrated Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://semanticscience.org/resource/SIO_$1"),
+        license: None,
+    },
+    so, SO_PATTERN => "^SO:\\d{7}$" {
+        prefix: "SO",
+        name: "Sequence types and features ontology",
+        synonyms: &["SOID"],
+        description: Some("The Sequence Ontology is a set of terms and relationships used to describe the features and attributes of biological sequence."),
+        homepage: Some("http://www.sequenceontology.org/"),
+        example: Some("0000704"),
+        banana: Some("SO:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/SO_$1"),
+        license: Some("CC-BY-4.0"),
+    },
+    spd, SPD_PATTERN => "^SPD:\\d{7}$" {
+        prefix: "SPD",
+        name: "Spider Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/SPD_$1"),
+        license: None,
+    },
+    stato, STATO_PATTERN => "^STATO:\\d{7}$" {
+        prefix: "STATO",
+        name: "Statistics Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/STATO_$1"),
+        license: None,
+    },
+    sty, STY_PATTERN => "^STY:T\\d{3}$" {
+        prefix: "STY",
+        name: "UMLS Semantic Types Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.bioontology.org/ontology/STY/$1"),
+        license: None,
+    },
+    swo, SWO_PATTERN => "^SWO:\\d{7,8}$" {
+        prefix: "SWO",
+        name: "Software ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/SWO_$1"),
+        license: None,
+    },
+    symp, SYMP_PATTERN => "^SYMP:\\d{7}$" {
+        prefix: "SYMP",
+        name: "Symptom Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/SYMP_$1"),
+        license: None,
+    },
+    t4fs, T4FS_PATTERN => "^T4FS:\\d{7}$" {
+        prefix: "T4FS",
+        name: "terms4FAIRskills",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/T4FS_$1"),
+        license: None,
+    },
+    tads, TADS_PATTERN => "^TADS:\\d{7}$" {
+        prefix: "TADS",
+        name: "Tick Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/TADS_$1"),
+        license: None,
+    },
+    tao, TAO_PATTERN => "^TAO:\\d{7}$" {
+        prefix: "TAO",
+        name: "Teleost Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/TAO_$1"),
+        license: None,
+    },
+    taxrank, TAXRANK_PATTERN => "^TAXRANK:\\d{7}$" {
+        prefix: "TAXRANK",
+        name: "Taxonomic rank vocabulary",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/TAXRANK_$1"),
+        license: None,
+    },
+    teddy, TEDDY_PATTERN => "^TEDDY:\\d+$" {
+        prefix: "TEDDY",
+        name: "Terminology for Description of Dynamics",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: None,
+        license: None,
+    },
+    tgma, TGMA_PATTERN => "^TGMA:\\d{7}$" {
+        prefix: "TGMA",
+        name: "Mosquito gross anatomy ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/TGMA_$1"),
+        license: None,
+    },
+    to, TO_PATTERN => "^TO:\\d{7}$" {
+        prefix: "TO",
+        name: "Plant Trait Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/TO_$1"),
+        license: None,
+    },
+    trans, TRANS_PATTERN => "^TRANS:\\d{7}$" {
+        prefix: "TRANS",
+        name: "Pathogen Transmission Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/TRANS_$1"),
+        license: None,
+    },
+    tto, TTO_PATTERN => "^TTO:\\d+$" {
+        prefix: "TTO",
+        name: "Teleost taxonomy ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/TTO_$1"),
+        license: None,
+    },
+    txpo, TXPO_PATTERN => "^TXPO:\\d{7}$" {
+        prefix: "TXPO",
+        name: "Toxic Process Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/TXPO_$1"),
+        license: None,
+    },
+    uberon, UBERON_PATTERN => "^UBERON:\\d+$" {
+        prefix: "UBERON",
+        name: "Uber Anatomy Ontology",
+        synonyms: &[],
+        description: Some("Uberon is an integrated cross-species anatomy ontology representing a variety of entities classified according to traditional anatomical criteria such as structure, function and developmental lineage."),
+        homepage: Some("http://uberon.org"),
+        example: Some("0000061"),
+        banana: Some("UBERON:"),
+        uri_format: Some("http://purl.obolibrary.org/obo/UBERON_$1"),
+        license: Some("CC-BY-3.0"),
+    },
+    uo, UO_PATTERN => "^UO:\\d{7}$" {
+        prefix: "UO",
+        name: "Units of measurement ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/UO_$1"),
+        license: None,
+    },
+    upa, UPA_PATTERN => "^UPA:(UCR|UCY|UER|ULS|UPA|UPC|UPX)\\d{5}$" {
+        prefix: "UPA",
+        name: "Unipathway",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/UPA_$1"),
+        license: None,
+    },
+    vandf, VANDF_PATTERN => "^VANDF:\\d+$" {
+        prefix: "VANDF",
+        name: "Veterans Administration National Drug File",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.bioontology.org/ontology/VANDF/$1"),
+        license: None,
+    },
+    vario, VARIO_PATTERN => "^VariO:\\d+$" {
+        prefix: "VariO",
+        name: "Variation Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/VariO_$1"),
+        license: None,
+    },
+    vbo, VBO_PATTERN => "^VBO:\\d{7}$" {
+        prefix: "VBO",
+        name: "Vertebrate Breed Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/VBO_$1"),
+        license: None,
+    },
+    vhog, VHOG_PATTERN => "^VHOG:\\d{7}$" {
+        prefix: "VHOG",
+        name: "Vertebrate Homologous Organ Group Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/VHOG_$1"),
+        license: None,
+    },
+    vido, VIDO_PATTERN => "^VIDO:\\d{7}$" {
+        prefix: "VIDO",
+        name: "Virus Infectious Disease Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/VIDO_$1"),
+        license: None,
+    },
+    vo, VO_PATTERN => "^VO:\\d{7}$" {
+        prefix: "VO",
+        name: "Vaccine Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/VO_$1"),
+        license: None,
+    },
+    vsao, VSAO_PATTERN => "^VSAO:\\d{7}$" {
+        prefix: "VSAO",
+        name: "Vertebrate Skeletal Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/VSAO_$1"),
+        license: None,
+    },
+    vso, VSO_PATTERN => "^VSO:\\d{7}$" {
+        prefix: "VSO",
+        name: "Vital Sign Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/VSO_$1"),
+        license: None,
+    },
+    vt, VT_PATTERN => "^VT:\\d{7}$" {
+        prefix: "VT",
+        name: "Vertebrate trait ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/VT_$1"),
+        license: None,
+    },
+    vto, VTO_PATTERN => "^VTO:\\d{7}$" {
+        prefix: "VTO",
+        name: "Vertebrate Taxonomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/VTO_$1"),
+        license: None,
+    },
+    wbbt, WBBT_PATTERN => "^WBbt:\\d{7}$" {
+        prefix: "WBbt",
+        name: "C. elegans Gross Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/WBbt_$1"),
+        license: None,
+    },
+    wbls, WBLS_PATTERN => "^WBls:\\d{7}$" {
+        prefix: "WBls",
+        name: "C. elegans development ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/WBls_$1"),
+        license: None,
+    },
+    wbphenotype, WBPHENOTYPE_PATTERN => "^WBPhenotype:\\d{7}$" {
+        prefix: "WBPhenotype",
+        name: "C. elegans phenotype",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/WBPhenotype_$1"),
+        license: None,
+    },
+    xao, XAO_PATTERN => "^XAO:\\d{7}$" {
+        prefix: "XAO",
+        name: "Xenopus Anatomy Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/XAO_$1"),
+        license: None,
+    },
+    xco, XCO_PATTERN => "^XCO:\\d{7}$" {
+        prefix: "XCO",
+        name: "Experimental condition ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/XCO_$1"),
+        license: None,
+    },
+    xlmod, XLMOD_PATTERN => "^XLMOD:\\d{5}$" {
+        prefix: "XLMOD",
+        name: "HUPO-PSI cross-linking and derivatization reagents controlled vocabulary",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/XLMOD_$1"),
+        license: None,
+    },
+    xpo, XPO_PATTERN => "^XPO:\\d+$" {
+        prefix: "XPO",
+        name: "Xenopus Phenotype Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/XPO_$1"),
+        license: None,
+    },
+    zea, ZEA_PATTERN => "^ZEA:\\d{7}$" {
+        prefix: "ZEA",
+        name: "Maize gross anatomy",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ZEA_$1"),
+        license: None,
+    },
+    zeco, ZECO_PATTERN => "^ZECO:\\d{7}$" {
+        prefix: "ZECO",
+        name: "Zebrafish Experimental Conditions Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ZECO_$1"),
+        license: None,
+    },
+    zfa, ZFA_PATTERN => "^ZFA:\\d{7}$" {
+        prefix: "ZFA",
+        name: "Zebrafish anatomy and development ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ZFA_$1"),
+        license: None,
+    },
+    zfs, ZFS_PATTERN => "^ZFS:\\d{7}$" {
+        prefix: "ZFS",
+        name: "Zebrafish developmental stages ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ZFS_$1"),
+        license: None,
+    },
+    zp, ZP_PATTERN => "^ZP:\\d+$" {
+        prefix: "ZP",
+        name: "Zebrafish Phenotype Ontology",
+        synonyms: &[],
+        description: None,
+        homepage: None,
+        example: None,
+        banana: None,
+        uri_format: Some("http://purl.obolibrary.org/obo/ZP_$1"),
+        license: None,
+    },
+}
 
-        impl CurieParser<CurieRegexValidator> {
-            $(
-                pub fn $fn_name() -> Self {
-                    CurieParser {
-                        validator: CurieRegexValidator::$fn_name(),
-                    }
-                }
-            )*
+/// The preferred prefixes of all ontologies in ASCII lowercase, sorted, with the index of their [`Ontology`] in
+/// [`Ontology::ALL`].
+#[rustfmt::skip]
+pub(crate) static PREFIXES_LOWERCASE: &[(&str, u16)] = &[
+    ("adcad", 0),
+    ("ado", 1),
+    ("aeo", 2),
+    ("aero", 3),
+    ("agro", 4),
+    ("agrovoc", 5),
+    ("aism", 6),
+    ("amphx", 7),
+    ("apo", 8),
+    ("apollo_sv", 9),
+    ("aro", 10),
+    ("ato", 11),
+    ("atol", 12),
+    ("bcgo", 13),
+    ("bcio", 14),
+    ("bco", 15),
+    ("bervo", 16),
+    ("bfo", 17),
+    ("birnlex", 18),
+    ("bko", 19),
+    ("bspo", 20),
+    ("bto", 21),
+    ("cao", 22),
+    ("caro", 23),
+    ("ccf", 24),
+    ("cco", 25),
+    ("cdao", 26),
+    ("cdno", 27),
+    ("ceph", 28),
+    ("chebi", 29),
+    ("cheminf", 30),
+    ("chmo", 31),
+    ("cido", 32),
+    ("cio", 33),
+    ("cl", 34),
+    ("clao", 35),
+    ("clo", 36),
+    ("clyh", 37),
+    ("cmecs", 38),
+    ("cmo", 39),
+    ("cmpo", 40),
+    ("cob", 41),
+    ("colao", 42),
+    ("cpt", 43),
+    ("cro", 44),
+    ("cryoem", 45),
+    ("ctcae", 46),
+    ("cteno", 47),
+    ("cto", 48),
+    ("cvdo", 49),
+    ("dcm", 50),
+    ("ddanat", 51),
+    ("ddpheno", 52),
+    ("dermo", 53),
+    ("dideo", 54),
+    ("disdriv", 55),
+    ("doid", 56),
+    ("dron", 57),
+    ("drugcentral", 58),
+    ("dto", 59),
+    ("duo", 60),
+    ("ecao", 61),
+    ("ecg", 62),
+    ("eco", 63),
+    ("ecocore", 64),
+    ("ecso", 65),
+    ("ecto", 66),
+    ("ehda", 67),
+    ("ehdaa", 68),
+    ("ehdaa2", 69),
+    ("emap", 70),
+    ("emapa", 71),
+    ("enm", 72),
+    ("envo", 73),
+    ("eol", 74),
+    ("epio", 75),
+    ("epso", 76),
+    ("ero", 77),
+    ("eupath", 78),
+    ("exo", 79),
+    ("fao", 80),
+    ("fbbi", 81),
+    ("fbbt", 82),
+    ("fbcv", 83),
+    ("fbdv", 84),
+    ("fideo", 85),
+    ("fix", 86),
+    ("flopo", 87),
+    ("flu", 88),
+    ("fma", 89),
+    ("fobi", 90),
+    ("foodon", 91),
+    ("fovt", 92),
+    ("fypo", 93),
+    ("gallont", 94),
+    ("gaz", 95),
+    ("gecko", 96),
+    ("genepio", 97),
+    ("geno", 98),
+    ("geo", 99),
+    ("gno", 100),
+    ("go", 101),
+    ("gpml", 102),
+    ("gsso", 103),
+    ("hancestro", 104),
+    ("hao", 105),
+    ("hgnc", 106),
+    ("hoip", 107),
+    ("hom", 108),
+    ("hp", 109),
+    ("hsapdv", 110),
+    ("hso", 111),
+    ("htn", 112),
+    ("icd10", 113),
+    ("icd10cm", 114),
+    ("icd9cm", 115),
+    ("iceo", 116),
+    ("icf", 117),
+    ("ico", 118),
+    ("ido", 119),
+    ("idoden", 120),
+    ("idomal", 121),
+    ("ino", 122),
+    ("ito", 123),
+    ("kisao", 124),
+    ("labo", 125),
+    ("lbo", 126),
+    ("lepao", 127),
+    ("loinc", 128),
+    ("lpt", 129),
+    ("ma", 130),
+    ("mamo", 131),
+    ("mat", 132),
+    ("maxo", 133),
+    ("mco", 134),
+    ("mcro", 135),
+    ("mesh", 136),
+    ("metpo", 137),
+    ("mf", 138),
+    ("mfmo", 139),
+    ("mfo", 140),
+    ("mfoem", 141),
+    ("mfomd", 142),
+    ("mi", 143),
+    ("miapa", 144),
+    ("micro", 145),
+    ("miro", 146),
+    ("mixs", 147),
+    ("mmo", 148),
+    ("mmusdv", 149),
+    ("mondo", 150),
+    ("mop", 151),
+    ("mosaic", 152),
+    ("mp", 153),
+    ("mpath", 154),
+    ("mpio", 155),
+    ("mro", 156),
+    ("ms", 157),
+    ("mwo", 158),
+    ("nando", 159),
+    ("nbo", 160),
+    ("ncbitaxon", 161),
+    ("ncit", 162),
+    ("ncro", 163),
+    ("nddf", 164),
+    ("ngbo", 165),
+    ("nifstd", 166),
+    ("nmr", 167),
+    ("nomen", 168),
+    ("npo", 169),
+    ("oae", 170),
+    ("oarcs", 171),
+    ("oba", 172),
+    ("obcs", 173),
+    ("obi", 174),
+    ("obib", 175),
+    ("occo", 176),
+    ("ogg", 177),
+    ("ogi", 178),
+    ("ogms", 179),
+    ("ogsf", 180),
+    ("ohd", 181),
+    ("ohmi", 182),
+    ("ohpi", 183),
+    ("olatdv", 184),
+    ("omiabis", 185),
+    ("omim", 186),
+    ("omo", 187),
+    ("omp", 188),
+    ("omrse", 189),
+    ("one", 190),
+    ("ons", 191),
+    ("ontoavida", 192),
+    ("ontoneo", 193),
+    ("oostt", 194),
+    ("opb", 195),
+    ("opl", 196),
+    ("opmi", 197),
+    ("ordo", 199),
+    ("ornaseq", 198),
+    ("ovae", 200),
+    ("pato", 201),
+    ("pav", 202),
+    ("pcl", 203),
+    ("pco", 204),
+    ("pdro", 205),
+    ("pdumdv", 206),
+    ("peco", 207),
+    ("phenx", 208),
+    ("phipo", 209),
+    ("plana", 210),
+    ("planp", 211),
+    ("plosthes", 212),
+    ("po", 213),
+    ("poro", 214),
+    ("ppo", 215),
+    ("pr", 216),
+    ("proco", 217),
+    ("psdo", 218),
+    ("pso", 219),
+    ("pw", 220),
+    ("radlex", 221),
+    ("rbo", 222),
+    ("reo", 223),
+    ("rex", 224),
+    ("rnao", 225),
+    ("ro", 226),
+    ("roleo", 227),
+    ("rs", 228),
+    ("rxno", 229),
+    ("rxnorm", 230),
+    ("salmon", 231),
+    ("sao", 232),
+    ("sasap", 233),
+    ("sbo", 234),
+    ("scdo", 235),
+    ("sdgio", 236),
+    ("senso", 237),
+    ("sep", 238),
+    ("sepio", 239),
+    ("sibo", 240),
+    ("sio", 241),
+    ("so", 242),
+    ("spd", 243),
+    ("stato", 244),
+    ("sty", 245),
+    ("swo", 246),
+    ("symp", 247),
+    ("t4fs", 248),
+    ("tads", 249),
+    ("tao", 250),
+    ("taxrank", 251),
+    ("teddy", 252),
+    ("tgma", 253),
+    ("to", 254),
+    ("trans", 255),
+    ("tto", 256),
+    ("txpo", 257),
+    ("uberon", 258),
+    ("uo", 259),
+    ("upa", 260),
+    ("vandf", 261),
+    ("vario", 262),
+    ("vbo", 263),
+    ("vhog", 264),
+    ("vido", 265),
+    ("vo", 266),
+    ("vsao", 267),
+    ("vso", 268),
+    ("vt", 269),
+    ("vto", 270),
+    ("wbbt", 271),
+    ("wbls", 272),
+    ("wbphenotype", 273),
+    ("xao", 274),
+    ("xco", 275),
+    ("xlmod", 276),
+    ("xpo", 277),
+    ("zea", 278),
+    ("zeco", 279),
+    ("zfa", 280),
+    ("zfs", 281),
+    ("zp", 282),
+];
 
-            pub fn from_prefix(prefix: &str) -> Option<Self> {
-                match prefix.to_lowercase().as_str() {
-                    $(
-                        stringify!($fn_name) => Some(Self::$fn_name()),
-                    )*
-                    _ => None,
-                }
-            }
-        }
-    };
-}
+/// The Bioregistry synonyms of all ontologies in ASCII lowercase, sorted, with the index of their [`Ontology`] in
+/// [`Ontology::ALL`].
+#[rustfmt::skip]
+pub(crate) static SYNONYMS_LOWERCASE: &[(&str, u16)] = &[
+    ("chebiid", 29),
+    ("do", 56),
+    ("drug_central", 58),
+    ("fmaid", 89),
+    ("gobp", 101),
+    ("gocc", 101),
+    ("gomf", 101),
+    ("hp_o", 109),
+    ("hpo", 109),
+    ("human_phenotype_ontology", 109),
+    ("icd-10", 113),
+    ("icd-10-cm", 114),
+    ("icd-9-cm", 115),
+    ("icd10who", 113),
+    ("lnc", 128),
+    ("mesh.2012", 136),
+    ("mesh.2013", 136),
+    ("mim", 186),
+    ("msh", 136),
+    ("ncbi.taxon", 161),
+    ("ncbi_taxid", 161),
+    ("ncbi_taxon", 161),
+    ("nci", 162),
+    ("nci_thesaurus", 162),
+    ("ncithesaurus", 162),
+    ("orphanet", 199),
+    ("pro", 216),
+    ("rxcui", 230),
+    ("soid", 242),
+    ("taxid", 161),
+    ("taxon", 161),
+    ("uniprot.taxonomy", 161),
+];
 
-define_curie_validators! {mi, MI_PATTERN => "^MI:\\d{4}$",
-// Human Reference Atlas Common Coordinate Framework Ontology
-ccf, CCF_PATTERN => "^CCF:\\S+$",
-agro, AGRO_PATTERN => "^AGRO:\\d{8}$",
-// Homeostasis imbalance process ontology
-hoip, HOIP_PATTERN => "^HOIP:\\d{7}$",
-sbo, SBO_PATTERN => "^SBO:\\d{7}$",
-fbbt, FBBT_PATTERN => "^FBbt:\\d{8}$",
-upa, UPA_PATTERN => "^UPA:(UCR|UCY|UER|ULS|UPA|UPC|UPX)\\d{5}$",
-// Chemical Entities of Biological Interest
-chebi, CHEBI_PATTERN => "^CHEBI:\\d+$",
-mro, MRO_PATTERN => "^MRO:\\d{7}$",
-lbo, LBO_PATTERN => "^LBO:\\d{7}$",
-uo, UO_PATTERN => "^UO:\\d{7}$",
-rnao, RNAO_PATTERN => "^RNAO:\\d{7}$",
-wbbt, WBBT_PATTERN => "^WBbt:\\d{7}$",
-// Gender, Sex, and Sexual Orientation Ontology
-gsso, GSSO_PATTERN => "^GSSO:\\d{6}$",
-eupath, EUPATH_PATTERN => "^EUPATH:\\d{7}$",
-disdriv, DISDRIV_PATTERN => "^DISDRIV:\\d+$",
-tto, TTO_PATTERN => "^TTO:\\d+$",
-fix, FIX_PATTERN => "^FIX:\\d{7}$",
-cl, CL_PATTERN => "^CL:\\d{7}$",
-sasap, SASAP_PATTERN => "^SASAP:\\d+$",
-ito, ITO_PATTERN => "^ITO:\\d+$",
-// Drug Target Ontology
-dto, DTO_PATTERN => "^DTO:\\d+$",
-oostt, OOSTT_PATTERN => "^OOSTT:\\d{8}$",
-// Human Dermatological Disease Ontology
-dermo, DERMO_PATTERN => "^DERMO:\\d{7}$",
-// Food-Biomarker Ontology
-fobi, FOBI_PATTERN => "^FOBI:\\d{4,6}$",
-// Microbial Ecophysiological Trait and Phenotype Ontology
-metpo, METPO_PATTERN => "^METPO:\\d+$",
-// Teleost Anatomy Ontology
-tao, TAO_PATTERN => "^TAO:\\d{7}$",
-// Minimum Information about any (x) Sequence
-mixs, MIXS_PATTERN => "^MIXS:\\d{7}$",
-fideo, FIDEO_PATTERN => "^FIDEO:\\d+$",
-trans, TRANS_PATTERN => "^TRANS:\\d{7}$",
-ms, MS_PATTERN => "^MS:\\d{7}$",
-clyh, CLYH_PATTERN => "^CLYH:\\d+$",
-ero, ERO_PATTERN => "^ERO:\\d{7}$",
-peco, PECO_PATTERN => "^PECO:\\d{7}$",
-ohmi, OHMI_PATTERN => "^OHMI:\\d{7}$",
-pco, PCO_PATTERN => "^PCO:\\d{7}$",
-// Relation Ontology
-ro, RO_PATTERN => "^RO:(HOM)?\\d{7}$",
-mfoem, MFOEM_PATTERN => "^MFOEM:\\d{6}$",
-ehda, EHDA_PATTERN => "^EHDA:\\d+$",
-mf, MF_PATTERN => "^MF:\\d{7}$",
-lepao, LEPAO_PATTERN => "^LEPAO:\\d{7}$",
-cdao, CDAO_PATTERN => "^CDAO:\\d{7}$",
-chmo, CHMO_PATTERN => "^CHMO:\\d{7}$",
-// HUGO Gene Nomenclature Committee
-hgnc, HGNC_PATTERN => "^HGNC:\\d{1,5}$",
-// PhenX Toolkit
-phenx, PHENX_PATTERN => "^PHENX:\\d+$",
-mpath, MPATH_PATTERN => "^MPATH:\\d+$",
-mco, MCO_PATTERN => "^MCO:\\d+$",
-aeo, AEO_PATTERN => "^AEO:\\d{7}$",
-ppo, PPO_PATTERN => "^PPO:\\d{7}$",
-mcro, MCRO_PATTERN => "^MCRO:\\d{7}$",
-obcs, OBCS_PATTERN => "^OBCS:\\d{7}$",
-// Livestock Product Trait Ontology
-lpt, LPT_PATTERN => "^LPT:\\d+$",
-aro, ARO_PATTERN => "^ARO:\\d{7}$",
-salmon, SALMON_PATTERN => "^SALMON:\\d+$",
-txpo, TXPO_PATTERN => "^TXPO:\\d{7}$",
-// Online Mendelian Inheritance in Man
-omim, OMIM_PATTERN => "^OMIM:\\d+$",
-zfs, ZFS_PATTERN => "^ZFS:\\d{7}$",
-// National Drug Data File
-nddf, NDDF_PATTERN => "^NDDF:\\d{6}$",
-xao, XAO_PATTERN => "^XAO:\\d{7}$",
-spd, SPD_PATTERN => "^SPD:\\d{7}$",
-zp, ZP_PATTERN => "^ZP:\\d+$",
-bcio, BCIO_PATTERN => "^BCIO:\\d{6}$",
-psdo, PSDO_PATTERN => "^PSDO:\\d{7}$",
-go, GO_PATTERN => "^GO:\\d{7}$",
-opb, OPB_PATTERN => "^OPB:\\d+$",
-// Mondo Disease Ontology
-mondo, MONDO_PATTERN => "^MONDO:\\d{7}$",
-mamo, MAMO_PATTERN => "^MAMO:\\d{7}$",
-ncro, NCRO_PATTERN => "^NCRO:\\d{7}$",
-htn, HTN_PATTERN => "^HTN:\\d{8}$",
-// Agronomy Vocabulary
-agrovoc, AGROVOC_PATTERN => "^AGROVOC:[a-z0-9]+$",
-ordo, ORDO_PATTERN => "^ORDO:C?\\d+$",
-roleo, ROLEO_PATTERN => "^ROLEO:\\d{7}$",
-bco, BCO_PATTERN => "^BCO:\\d{7}$",
-apo, APO_PATTERN => "^APO:\\d{7}$",
-ceph, CEPH_PATTERN => "^CEPH:\\d{7}$",
-cio, CIO_PATTERN => "^CIO:\\d{7}$",
-ecso, ECSO_PATTERN => "^ECSO:\\d+$",
-dcm, DCM_PATTERN => "^DCM:\\d+$",
-mp, MP_PATTERN => "^MP:\\d{7}$",
-fbcv, FBCV_PATTERN => "^FBcv:\\d{7}$",
-exo, EXO_PATTERN => "^ExO:\\d{7}$",
-rs, RS_PATTERN => "^RS:\\d{7}$",
-obib, OBIB_PATTERN => "^OBIB:\\d{7}$",
-ohd, OHD_PATTERN => "^OHD:\\d{7}$",
-omrse, OMRSE_PATTERN => "^OMRSE:\\d{8}$",
-omo, OMO_PATTERN => "^OMO:\\d{7}$",
-cteno, CTENO_PATTERN => "^CTENO:\\d{7}$",
-mfmo, MFMO_PATTERN => "^MFMO:\\d{7}$",
-ontoneo, ONTONEO_PATTERN => "^ONTONEO:\\d{8}$",
-labo, LABO_PATTERN => "^LABO:\\d{7}$",
-clo, CLO_PATTERN => "^CLO:\\d{7}$",
-phipo, PHIPO_PATTERN => "^PHIPO:\\d{7}$",
-vso, VSO_PATTERN => "^VSO:\\d{7}$",
-wbls, WBLS_PATTERN => "^WBls:\\d{7}$",
-aero, AERO_PATTERN => "^AERO:\\d{7}$",
-hsapdv, HSAPDV_PATTERN => "^HsapDv:\\d{7}$",
-taxrank, TAXRANK_PATTERN => "^TAXRANK:\\d{7}$",
-// Arctic Data Center Academic Disciplines Ontology
-adcad, ADCAD_PATTERN => "^ADCAD:\\d{5}$",
-// International Classification of Diseases, 10th Revision, Clinical Modification
-icd10cm, ICD10CM_PATTERN => "^ICD10CM:([A-Z][0-9][0-9AB]((-[A-Z][0-9][0-9AB])|(\\.[0-9A-KXZ]([0-9A-EXYZ]([0-9A-HX][0-59A-HJKMNP-S]?)?)?)?))$",
-vto, VTO_PATTERN => "^VTO:\\d{7}$",
-// Foundational Model of Anatomy
-fma, FMA_PATTERN => "^FMA:\\d+$",
-tgma, TGMA_PATTERN => "^TGMA:\\d{7}$",
-// NFDI MatWerk Ontology
-mwo, MWO_PATTERN => "^MWO:\\d{7}$",
-hom, HOM_PATTERN => "^HOM:\\d{7}$",
-// A nomenclatural ontology for biological names
-nomen, NOMEN_PATTERN => "^NOMEN:\\d{7}$",
-// Molecular Process Ontology
-mop, MOP_PATTERN => "^MOP:\\d{7}$",
-dideo, DIDEO_PATTERN => "^DIDEO:\\d{8}$",
-xpo, XPO_PATTERN => "^XPO:\\d+$",
-micro, MICRO_PATTERN => "^MICRO:\\d{7}$",
-to, TO_PATTERN => "^TO:\\d{7}$",
-rex, REX_PATTERN => "^REX:\\d{7}$",
-// Electrocardiogram Ontology
-ecg, ECG_PATTERN => "^ECG:\\d+$",
-ornaseq, ORNASEQ_PATTERN => "^ORNASEQ:\\d{7}$",
-occo, OCCO_PATTERN => "^OCCO:\\d+$",
-cob, COB_PATTERN => "^COB:\\d{7}$",
-opl, OPL_PATTERN => "^OPL:\\d{7}$",
-cvdo, CVDO_PATTERN => "^CVDO:\\d{7}$",
-// Reagent Ontology
-reo, REO_PATTERN => "^REO:\\d{7}$",
-// NIF Standard Ontology
-nifstd, NIFSTD_PATTERN => "^NIFSTD:BAMSC\\d+$",
-ovae, OVAE_PATTERN => "^OVAE:\\d{7}$",
-omiabis, OMIABIS_PATTERN => "^OMIABIS:\\d{7}$",
-// Vertebrate Homologous Organ Group Ontology
-vhog, VHOG_PATTERN => "^VHOG:\\d{7}$",
-// UMLS Semantic Types Ontology
-sty, STY_PATTERN => "^STY:T\\d{3}$",
-miro, MIRO_PATTERN => "^MIRO:\\d{8}$",
-ato, ATO_PATTERN => "^ATO:\\d{7}$",
-// Coleoptera Anatomy Ontology
-colao, COLAO_PATTERN => "^COLAO:\\d{7}$",
-so, SO_PATTERN => "^SO:\\d{7}$",
-wbphenotype, WBPHENOTYPE_PATTERN => "^WBPhenotype:\\d{7}$",
-// Uber Anatomy Ontology
-uberon, UBERON_PATTERN => "^UBERON:\\d+$",
-geo, GEO_PATTERN => "^GEO:\\d{9}$",
-cmpo, CMPO_PATTERN => "^CMPO:\\d{7}$",
-// Veterans Administration National Drug File
-vandf, VANDF_PATTERN => "^VANDF:\\d+$",
-// Ontology for Avida digital evolution platform
-ontoavida, ONTOAVIDA_PATTERN => "^ONTOAVIDA:\\d{8}$",
-// International Classification of Functioning, Disability and Health
-icf, ICF_PATTERN => "^ICF:\\d+$",
-teddy, TEDDY_PATTERN => "^TEDDY:\\d+$",
-pw, PW_PATTERN => "^PW:\\d{7}$",
-// Planarian Anatomy and Schmidtea mediterranean Developmental Stage Ontology
-plana, PLANA_PATTERN => "^PLANA:\\d{7}$",
-emap, EMAP_PATTERN => "^EMAP:\\d+$",
-// Glycan Naming and Subsumption Ontology
-gno, GNO_PATTERN => "^GNO:(\\d{8}|(\\w+\\d+\\w+))$",
-vt, VT_PATTERN => "^VT:\\d{7}$",
-xco, XCO_PATTERN => "^XCO:\\d{7}$",
-hso, HSO_PATTERN => "^HSO:\\d{7}$",
-pdro, PDRO_PATTERN => "^PDRO:\\d{7}$",
-sepio, SEPIO_PATTERN => "^SEPIO:\\d{7}$",
-bfo, BFO_PATTERN => "^BFO:\\d{7}$",
-epso, EPSO_PATTERN => "^EPSO:\\d{7}$",
-// Common Terminology Criteria for Adverse Events
-ctcae, CTCAE_PATTERN => "^CTCAE:E\\d+$",
-stato, STATO_PATTERN => "^STATO:\\d{7}$",
-vido, VIDO_PATTERN => "^VIDO:\\d{7}$",
-ons, ONS_PATTERN => "^ONS:\\d{7}$",
-envo, ENVO_PATTERN => "^ENVO:\\d{7,8}$",
-proco, PROCO_PATTERN => "^PROCO:\\d{7}$",
-// Chemical Information Ontology
-cheminf, CHEMINF_PATTERN => "^CHEMINF:\\d{6}$",
-// Name Reaction Ontology
-rxno, RXNO_PATTERN => "^RXNO:\\d{7}$",
-ecto, ECTO_PATTERN => "^ECTO:\\d{7}$",
-po, PO_PATTERN => "^PO:\\d+$",
-ohpi, OHPI_PATTERN => "^OHPI:\\d+$",
-// SBGN Bricks data and ontology
-bko, BKO_PATTERN => "^BKO:\\d+$",
-// Provenance, Authoring, and Versioning Vocabulary
-pav, PAV_PATTERN => "^PAV:[a-z][a-zA-Z]+$",
-nmr, NMR_PATTERN => "^NMR:\\d+$",
-ecocore, ECOCORE_PATTERN => "^ECOCORE:\\d+$",
-hancestro, HANCESTRO_PATTERN => "^HANCESTRO:\\d{4}$",
-mfo, MFO_PATTERN => "^MFO:\\d{7}$",
-gecko, GECKO_PATTERN => "^GECKO:\\d{7}$",
-cryoem, CRYOEM_PATTERN => "^CRYOEM:\\d{7}$",
-ogms, OGMS_PATTERN => "^OGMS:\\d{7}$",
-// Medical Subject Headings
-mesh, MESH_PATTERN => "^MESH:(C|D|Q)\\d+$",
-tads, TADS_PATTERN => "^TADS:\\d{7}$",
-// Variation Ontology
-vario, VARIO_PATTERN => "^VariO:\\d+$",
-zea, ZEA_PATTERN => "^ZEA:\\d{7}$",
-sibo, SIBO_PATTERN => "^SIBO:\\d{7}$",
-dron, DRON_PATTERN => "^DRON:\\d{8}$",
-ddanat, DDANAT_PATTERN => "^DDANAT:\\d{7}$",
-hao, HAO_PATTERN => "^HAO:\\d{7}$",
-// Ontology for the Anatomy of the Insect SkeletoMuscular system
-aism, AISM_PATTERN => "^AISM:\\d{7}$",
-emapa, EMAPA_PATTERN => "^EMAPA:\\d+$",
-ngbo, NGBO_PATTERN => "^NGBO:\\d{7}$",
-maxo, MAXO_PATTERN => "^MAXO:\\d{7}$",
-// International Classification of Diseases, 10th Revision
-icd10, ICD10_PATTERN => "^ICD10:(([XVI]+)|([A-Z][0-9]+((-[A-Z][0-9]+)|(\\.[0-9]))?))$",
-sdgio, SDGIO_PATTERN => "^SDGIO:\\d{8}$",
-// PLOS Thesaurus
-plosthes, PLOSTHES_PATTERN => "^PLOSTHES:\\d+$",
-cido, CIDO_PATTERN => "^CIDO:\\d{7}$",
-gaz, GAZ_PATTERN => "^GAZ:\\d{8}$",
-ogi, OGI_PATTERN => "^OGI:\\d{7}$",
-ehdaa2, EHDAA2_PATTERN => "^EHDAA2:\\d{7}$",
-// Chemical Analysis Ontology
-cao, CAO_PATTERN => "^CAO:\\d+$",
-// Dengue Fever Ontology
-idoden, IDODEN_PATTERN => "^IDODEN:\\d{7}$",
-iceo, ICEO_PATTERN => "^ICEO:\\d{7}(_\\d)?$",
-eol, EOL_PATTERN => "^EOL:\\d{7}$",
-cdno, CDNO_PATTERN => "^CDNO:\\d{7}$",
-planp, PLANP_PATTERN => "^PLANP:\\d+$",
-oba, OBA_PATTERN => "^OBA:(VT)?\\d{7}$",
-ehdaa, EHDAA_PATTERN => "^EHDAA:\\d+$",
-pato, PATO_PATTERN => "^PATO:\\d{7}$",
-ogg, OGG_PATTERN => "^OGG:\\d+$",
-doid, DOID_PATTERN => "^DOID:\\d+$",
-// Protein Ontology
-pr, PR_PATTERN => "^PR:(?:\\d{9}|[OPQ][0-9][A-Z0-9]{3}[0-9](?:-\\d+)?|[A-NR-Z][0-9](?:[A-Z][A-Z0-9]{2}[0-9]){1,2}(?:-\\d+)?)$",
-// BRENDA Tissue Ontology
-bto, BTO_PATTERN => "^BTO:\\d{7}$",
-vbo, VBO_PATTERN => "^VBO:\\d{7}$",
-mmusdv, MMUSDV_PATTERN => "^MmusDv:\\d{7}$",
-// terms4FAIRskills
-t4fs, T4FS_PATTERN => "^T4FS:\\d{7}$",
-// Biomedical Informatics Research Network Lexicon
-birnlex, BIRNLEX_PATTERN => "^BIRNLEX:\\d+$",
-fovt, FOVT_PATTERN => "^FOVT:\\d{7}$",
-pdumdv, PDUMDV_PATTERN => "^PdumDv:\\d{7}$",
-// Clinical Trials Ontology
-cto, CTO_PATTERN => "^CTO:\\d{7}$",
-kisao, KISAO_PATTERN => "^KISAO:\\d+$",
-// Minimum Anformation About a Phylogenetic Analysis Ontology
-miapa, MIAPA_PATTERN => "^MIAPA:\\d{7}$",
-oarcs, OARCS_PATTERN => "^OARCS:\\d{7}$",
-// Subcellular Anatomy Ontology
-sao, SAO_PATTERN => "^SAO:\\d+$",
-// Vertebrate Skeletal Anatomy Ontology
-vsao, VSAO_PATTERN => "^VSAO:\\d{7}$",
-zeco, ZECO_PATTERN => "^ZECO:\\d{7}$",
-pcl, PCL_PATTERN => "^PCL:\\d{7}$",
-// MOSAiC Ontology
-mosaic, MOSAIC_PATTERN => "^MOSAIC:\\d{8}$",
-mpio, MPIO_PATTERN => "^MPIO:\\d{7}$",
-scdo, SCDO_PATTERN => "^SCDO:\\d{7}$",
-duo, DUO_PATTERN => "^DUO:\\d{7}$",
-vo, VO_PATTERN => "^VO:\\d{7}$",
-rbo, RBO_PATTERN => "^RBO:\\d{6,8}$",
-eco, ECO_PATTERN => "^ECO:\\d{7}$",
-ico, ICO_PATTERN => "^ICO:\\d{7}$",
-// NCI Thesaurus
-ncit, NCIT_PATTERN => "^NCIT:[CRPA]\\d+$",
-cmo, CMO_PATTERN => "^CMO:\\d{7}$",
-// NanoParticle Ontology
-npo, NPO_PATTERN => "^NPO:\\d+$",
-flu, FLU_PATTERN => "^FLU:\\d{7}$",
-ddpheno, DDPHENO_PATTERN => "^DDPHENO:\\d{7}$",
-poro, PORO_PATTERN => "^PORO:\\d{7}$",
-// International Classification of Diseases, 9th Revision, Clinical Modification
-icd9cm, ICD9CM_PATTERN => "^ICD9CM:([\\dA-Z]\\d{2}(\\.\\d{1,3}|))|(\\d{2}(\\.\\d{1,2}|))$",
-ma, MA_PATTERN => "^MA:\\d+$",
-xlmod, XLMOD_PATTERN => "^XLMOD:\\d{5}$",
-olatdv, OLATDV_PATTERN => "^OlatDv:\\d{7}$",
-// Costal and Marine Ecological Classification Standard
-cmecs, CMECS_PATTERN => "^CMECS:\\d+$",
-caro, CARO_PATTERN => "^CARO:\\d{7}$",
-clao, CLAO_PATTERN => "^CLAO:\\d{7}$",
-bspo, BSPO_PATTERN => "^BSPO:\\d{7}$",
-epio, EPIO_PATTERN => "^EPIO:\\d{7}$",
-fao, FAO_PATTERN => "^FAO:\\d{7}$",
-idomal, IDOMAL_PATTERN => "^IDOMAL:(5?)\\d{7}$",
-symp, SYMP_PATTERN => "^SYMP:\\d{7}$",
-cco, CCO_PATTERN => "^CCO:\\w+$",
-// NCBI Taxonomy
-ncbitaxon, NCBITAXON_PATTERN => "^NCBITaxon:(\\d+)|([a-zA-Z_]+)$",
-fbdv, FBDV_PATTERN => "^FBdv:\\d{8}$",
-amphx, AMPHX_PATTERN => "^AMPHX:\\d+$",
-// WikiPathways GPML Vocabulary
-gpml, GPML_PATTERN => "^GPML:[A-Za-z]+$",
-opmi, OPMI_PATTERN => "^OPMI:\\d{7}$",
-ado, ADO_PATTERN => "^ADO:\\d{7}$",
-genepio, GENEPIO_PATTERN => "^GENEPIO:\\d{7}$",
-sep, SEP_PATTERN => "^SEP:\\d{5,6}$",
-senso, SENSO_PATTERN => "^SENSO:\\d+$",
-ogsf, OGSF_PATTERN => "^OGSF:\\d{7}$",
-mfomd, MFOMD_PATTERN => "^MFOMD:\\d{7}$",
-obi, OBI_PATTERN => "^OBI:\\d{7}$",
-cro, CRO_PATTERN => "^CRO:\\d{7}$",
-nbo, NBO_PATTERN => "^NBO:\\d{7}$",
-omp, OMP_PATTERN => "^OMP:\\d{7}$",
-ino, INO_PATTERN => "^INO:\\d{7}$",
-// RSNA Informatics RadLex
-radlex, RADLEX_PATTERN => "^RADLEX:RID\\d+$",
-zfa, ZFA_PATTERN => "^ZFA:\\d{7}$",
-swo, SWO_PATTERN => "^SWO:\\d{7,8}$",
-one, ONE_PATTERN => "^ONE:\\d{7}$",
-bcgo, BCGO_PATTERN => "^BCGO:\\d{7}$",
-nando, NANDO_PATTERN => "^NANDO:\\d+$",
-// RxNorm
-rxnorm, RXNORM_PATTERN => "^RXNORM:[0-9]{1,7}$",
-ido, IDO_PATTERN => "^IDO:\\d{7}$",
-flopo, FLOPO_PATTERN => "^FLOPO:\\d{7}$",
-// Fission Yeast Phenotype Ontology
-fypo, FYPO_PATTERN => "^FYPO:\\d{7}$",
-mmo, MMO_PATTERN => "^MMO:\\d{7}$",
-pso, PSO_PATTERN => "^PSO:\\d{7}$",
-ecao, ECAO_PATTERN => "^ECAO:\\d{7}$",
-geno, GENO_PATTERN => "^GENO:\\d{7}$",
-apollo_sv, APOLLO_SV_PATTERN => "^APOLLO_SV:\\d{8}$",
-fbbi, FBBI_PATTERN => "^FBbi:\\d+$",
-// Biological and Environmental Research Variable Ontology
-bervo, BERVO_PATTERN => "^BERVO:\\d{7}$",
-gallont, GALLONT_PATTERN => "^GALLONT:\\d{7}$",
-enm, ENM_PATTERN => "^ENM:\\d+$",
-oae, OAE_PATTERN => "^OAE:\\d{7}$",
-atol, ATOL_PATTERN => "^ATOL:\\d{7}$",
-// Logical Observation Identifiers Names and Codes
-loinc, LOINC_PATTERN => "^LOINC:(\\d|\\w)+-\\d$",
-// Human Phenotype Ontology
-hp, HP_PATTERN => "^HP:\\d{7}$",
-// The Food Ontology
-foodon, FOODON_PATTERN => "^FOODON:[0-9]{8}$",
-sio, SIO_PATTERN => "^SIO:\\d{6}$",
-// Minimal Anatomical Terminology
-mat, MAT_PATTERN => "^MAT:\\d{7}$",
-// Current Procedural Terminology
-cpt, CPT_PATTERN => "^CPT:\\d+$",
-    drugcentral, DRUGCENTRAL => "DrugCentral:\\d+$",
-    general, GENERAL_PATTERN => r"^[a-zA-Z0-9_][a-zA-Z0-9\._-]*:[^\s]+$"
-}
+#[deprecated(note = "renamed to `DRUGCENTRAL_PATTERN`")]
+pub const DRUGCENTRAL: &str = DRUGCENTRAL_PATTERN;
@@ -0,0 +1,135 @@
+use crate::error::InvalidCurieReason;
+use crate::metadata::OntologyMetadata;
+
+/// Finds structural problems that make a CURIE invalid regardless of its ontology, following
+/// the rules of [`GENERAL_PATTERN`](super::curie_patterns::GENERAL_PATTERN).
+///
+/// Returns `None` if the CURIE is well-formed.
+pub(crate) fn diagnose_structure(curie: &str) -> Option<InvalidCurieReason> {
+    let Some((prefix, reference)) = curie.split_once(':') else {
+        return Some(InvalidCurieReason::MissingSeparator);
+    };
+    if prefix.is_empty() {
+        return Some(InvalidCurieReason::EmptyPrefix);
+    }
+    if reference.is_empty() {
+        return Some(InvalidCurieReason::EmptyReference);
+    }
+
+    let illegal_in_prefix = prefix.char_indices().find(|&(offset, character)| {
+        let allowed = character.is_ascii_alphanumeric() || character == '_';
+        !(allowed || offset > 0 && matches!(character, '.' | '-'))
+    });
+    let illegal_in_reference = reference
+        .char_indices()
+        .find(|(_, character)| character.is_whitespace())
+        .map(|(offset, character)| (prefix.len() + 1 + offset, character));
+
+    illegal_in_prefix
+        .or(illegal_in_reference)
+        .map(|(offset, character)| InvalidCurieReason::IllegalCharacter { offset, character })
+}
+
+/// Explains why a CURIE does not match the pattern of the ontology with the given metadata.
+pub(crate) fn diagnose_ontology(
+    metadata: &'static OntologyMetadata,
+    curie: &str,
+) -> InvalidCurieReason {
+    if let Some(reason) = diagnose_structure(curie) {
+        return reason;
+    }
+
+    let (prefix, reference) = curie
+        .split_once(':')
+        .expect("well-formed CURIEs contain a colon");
+    if prefix != metadata.prefix() {
+        return InvalidCurieReason::PrefixMismatch {
+            expected: metadata.prefix(),
+            found: prefix.to_string(),
+        };
+    }
+    match metadata.banana() {
+        Some(banana) if reference.starts_with(banana) => InvalidCurieReason::Banana { banana },
+        _ => InvalidCurieReason::ReferenceMismatch {
+            pattern: metadata.pattern(),
+            example: metadata.example(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ontology::Ontology;
+
+    #[test]
+    fn test_diagnose_structure() {
+        assert_eq!(diagnose_structure("HP:0000118"), None);
+        assert_eq!(
+            diagnose_structure("HP0000118"),
+            Some(InvalidCurieReason::MissingSeparator)
+        );
+        assert_eq!(
+            diagnose_structure(":0000118"),
+            Some(InvalidCurieReason::EmptyPrefix)
+        );
+        assert_eq!(
+            diagnose_structure("HP:"),
+            Some(InvalidCurieReason::EmptyReference)
+        );
+    }
+
+    #[test]
+    fn test_diagnose_illegal_characters() {
+        assert_eq!(
+            diagnose_structure("HP :0000118"),
+            Some(InvalidCurieReason::IllegalCharacter {
+                offset: 2,
+                character: ' '
+            })
+        );
+        assert_eq!(
+            diagnose_structure("-HP:0000118"),
+            Some(InvalidCurieReason::IllegalCharacter {
+                offset: 0,
+                character: '-'
+            })
+        );
+        assert_eq!(
+            diagnose_structure("HP:0000\t118"),
+            Some(InvalidCurieReason::IllegalCharacter {
+                offset: 7,
+                character: '\t'
+            })
+        );
+        assert_eq!(diagnose_structure("go-plus.v2:a/b#c"), None);
+    }
+
+    #[test]
+    fn test_diagnose_ontology() {
+        let metadata = Ontology::Hp.metadata();
+
+        assert_eq!(
+            diagnose_ontology(metadata, "hp:0000118"),
+            InvalidCurieReason::PrefixMismatch {
+                expected: "HP",
+                found: "hp".to_string()
+            }
+        );
+        assert_eq!(
+            diagnose_ontology(metadata, "HP:123"),
+            InvalidCurieReason::ReferenceMismatch {
+                pattern: metadata.pattern(),
+                example: Some("0000118")
+            }
+        );
+        assert_eq!(
+            diagnose_ontology(metadata, "HP:HP:0000118"),
+            InvalidCurieReason::Banana { banana: "HP:" }
+        );
+        assert_eq!(
+            diagnose_ontology(metadata, "HP:"),
+            InvalidCurieReason::EmptyReference
+        );
+    }
+}
@@ -0,0 +1,201 @@
+/// Defines a validator and a parser constructor per ontology, plus the lookup tables
+/// derived from the ontologies' Bioregistry metadata.
+///
+/// The invocation lives in [`curie_patterns`](super::curie_patterns) and is generated by
+/// `cargo xtask codegen`.
+macro_rules! define_curie_validators {
+    ( $(
+        $fn_name:ident, $const_name:ident => $pattern:literal {
+            prefix: $prefix:literal,
+            name: $name:literal,
+            synonyms: $synonyms:expr,
+            description: $description:expr,
+            homepage: $homepage:expr,
+            example: $example:expr,
+            banana: $banana:expr,
+            uri_format: $uri_format:expr,
+            license: $license:expr $(,)?
+        }
+    ),* $(,)? ) => {
+
+        $(
+            pub const $const_name: &'static str = $pattern;
+        )*
+        pub const GENERAL_PATTERN: &'static str = r"^[a-zA-Z0-9_][a-zA-Z0-9\._-]*:[^\s]+$";
+
+        $(
+            paste! {
+                static [<$const_name _REGEX>]: OnceLock<regex::Regex> = OnceLock::new();
+            }
+        )*
+        static GENERAL_PATTERN_REGEX: OnceLock<regex::Regex> = OnceLock::new();
+
+        $(
+            paste! {
+                static [<$const_name _METADATA>]: OntologyMetadata = OntologyMetadata {
+                    prefix: $prefix,
+                    name: $name,
+                    synonyms: $synonyms,
+                    description: $description,
+                    homepage: $homepage,
+                    example: $example,
+                    banana: $banana,
+                    uri_format: $uri_format,
+                    license: $license,
+                    pattern: $const_name,
+                };
+            }
+        )*
+
+        /// The metadata of all ontologies, indexed by their [`Ontology`] variant.
+        pub(crate) static ONTOLOGIES: &[&OntologyMetadata] = &[
+            $(
+                paste! { &[<$const_name _METADATA>] },
+            )*
+        ];
+
+        static SUPPORTED_PREFIXES: &[&str] = &[
+            $(
+                $prefix,
+            )*
+        ];
+
+        /// Returns the preferred prefixes of all ontologies with a generated validator.
+        ///
+        /// Every prefix can be passed to [`CurieParser::from_prefix`],
+        /// [`CurieRegexValidator::from_prefix`] and [`OntologyMetadata::from_prefix`].
+        /// The prefixes are ordered by their Bioregistry identifier.
+        ///
+        /// ```
+        /// use securiety::{supported_prefixes, CurieParser};
+        ///
+        /// assert!(supported_prefixes().contains(&"MONDO"));
+        /// assert!(supported_prefixes().iter().all(|prefix| CurieParser::from_prefix(prefix).is_some()));
+        /// ```
+        pub fn supported_prefixes() -> &'static [&'static str] {
+            SUPPORTED_PREFIXES
+        }
+
+        paste! {
+            /// An ontology with a generated validator.
+            ///
+            /// Each variant corresponds to one of the [`supported_prefixes`], and `Display`s as
+            /// that prefix.
+            ///
+            /// ```
+            /// use securiety::{CurieParsing, Ontology};
+            ///
+            /// let ontology: Ontology = "mondo".parse().unwrap();
+            /// assert_eq!(ontology, Ontology::Mondo);
+            /// assert_eq!(ontology.to_string(), "MONDO");
+            /// assert!(ontology.parser().parse("MONDO:0006007").is_ok());
+            /// ```
+            #[non_exhaustive]
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+            pub enum Ontology {
+                $(
+                    #[doc = $name]
+                    [<$fn_name:camel>],
+                )*
+            }
+
+            impl Ontology {
+                /// All ontologies, in the same order as [`supported_prefixes`].
+                pub const ALL: &'static [Ontology] = &[
+                    $(
+                        Ontology::[<$fn_name:camel>],
+                    )*
+                ];
+
+                /// Returns the validator for CURIEs of this ontology.
+                pub fn validator(self) -> CurieRegexValidator {
+                    CurieRegexValidator::from(self.regex().clone()).with_metadata(self.metadata())
+                }
+
+                /// Returns the compiled pattern of this ontology, compiling it on first use.
+                pub(crate) fn regex(self) -> &'static regex::Regex {
+                    match self {
+                        $(
+                            Ontology::[<$fn_name:camel>] => [<$const_name _REGEX>].get_or_init(|| {
+                                regex::Regex::new($const_name)
+                                    .expect(concat!("Error compiling regex for ", stringify!($const_name)))
+                            }),
+                        )*
+                    }
+                }
+            }
+        }
+
+        paste! {
+            /// The `#[serde(with = "...")]` modules of all ontologies, re-exported as
+            /// [`crate::serde`].
+            #[cfg(feature = "serde")]
+            pub mod serde_with {
+                $(
+                    #[doc = concat!("(De)serializes a [`Curie`](crate::Curie) of the ", $name, ", see [`crate::serde`].")]
+                    pub mod $fn_name {
+                        use crate::curie::Curie;
+                        use crate::ontology::Ontology;
+
+                        pub fn serialize<S: ::serde::Serializer>(
+                            curie: &Curie,
+                            serializer: S,
+                        ) -> Result<S::Ok, S::Error> {
+                            ::serde::Serialize::serialize(curie, serializer)
+                        }
+
+                        pub fn deserialize<'de, D: ::serde::Deserializer<'de>>(
+                            deserializer: D,
+                        ) -> Result<Curie, D::Error> {
+                            crate::serde::deserialize_ontology(Ontology::[<$fn_name:camel>], deserializer)
+                        }
+                    }
+                )*
+            }
+        }
+
+        /// Returns the compiled [`GENERAL_PATTERN`], compiling it on first use.
+        pub(crate) fn general_regex() -> &'static regex::Regex {
+            GENERAL_PATTERN_REGEX.get_or_init(|| {
+                regex::Regex::new(GENERAL_PATTERN).expect("Error compiling regex for GENERAL_PATTERN")
+            })
+        }
+
+        impl CurieRegexValidator {
+            $(
+                paste! {
+                    pub fn $fn_name() -> Self {
+                        Ontology::[<$fn_name:camel>].validator()
+                    }
+                }
+            )*
+
+            pub fn general() -> Self {
+                Self::from(general_regex().clone())
+            }
+
+            pub fn from_prefix(prefix: &str) -> Option<Self> {
+                if prefix.eq_ignore_ascii_case("general") {
+                    return Some(Self::general());
+                }
+                Ontology::from_prefix(prefix).map(Ontology::validator)
+            }
+        }
+
+        impl CurieParser<CurieRegexValidator> {
+            $(
+                pub fn $fn_name() -> Self {
+                    CurieParser::new(CurieRegexValidator::$fn_name())
+                }
+            )*
+
+            pub fn general() -> Self {
+                CurieParser::new(CurieRegexValidator::general())
+            }
+
+            pub fn from_prefix(prefix: &str) -> Option<Self> {
+                CurieRegexValidator::from_prefix(prefix).map(CurieParser::new)
+            }
+        }
+    };
+}
@@ -1,2 +1,7 @@
+#[macro_use]
+mod macros;
+
+pub mod combinators;
 pub mod curie_patterns;
+pub(crate) mod diagnosis;
 pub mod regex_validator;
@@ -1,5 +1,7 @@
-use crate::error::InvalidRegexError;
+use crate::error::{InvalidCurieReason, InvalidRegexError, UnknownPrefixError};
+use crate::metadata::OntologyMetadata;
 use crate::traits::CurieValidation;
+use crate::validators::diagnosis::{diagnose_ontology, diagnose_structure};
 use regex::Regex;
 
 /// A CURIE validator that uses regular expressions to validate CURIE strings.
@@ -27,17 +29,73 @@ use regex::Regex;
 #[derive(Debug, Clone)]
 pub struct CurieRegexValidator {
     regex: Regex,
+    metadata: Option<&'static OntologyMetadata>,
+}
+
+impl CurieRegexValidator {
+    pub(crate) fn with_metadata(mut self, metadata: &'static OntologyMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Returns the metadata of the ontology this validator was generated for.
+    ///
+    /// Returns `None` for [`CurieRegexValidator::general`] and for validators built from a
+    /// custom regex.
+    ///
+    /// ```
+    /// use securiety::CurieRegexValidator;
+    ///
+    /// let metadata = CurieRegexValidator::mondo().metadata().unwrap();
+    /// assert_eq!(metadata.name(), "Mondo Disease Ontology");
+    ///
+    /// assert!(CurieRegexValidator::general().metadata().is_none());
+    /// ```
+    pub fn metadata(&self) -> Option<&'static OntologyMetadata> {
+        self.metadata
+    }
+
+    /// Returns the validator for the ontology with the given prefix, ignoring case.
+    ///
+    /// Like [`CurieRegexValidator::from_prefix`], but the error suggests the closest
+    /// supported prefixes, see [`UnknownPrefixError::suggestions`].
+    pub fn try_from_prefix(prefix: &str) -> Result<Self, UnknownPrefixError> {
+        Self::from_prefix(prefix).ok_or_else(|| UnknownPrefixError::new(prefix))
+    }
 }
 
 impl CurieValidation for CurieRegexValidator {
     fn validate(&self, curie: &str) -> bool {
         self.regex.is_match(curie)
     }
+
+    /// Explains why a CURIE does not match the regex of this validator.
+    ///
+    /// Validators of an ontology report a [`PrefixMismatch`](InvalidCurieReason::PrefixMismatch)
+    /// or a [`ReferenceMismatch`](InvalidCurieReason::ReferenceMismatch) with the pattern and
+    /// example of the ontology. Validators built from a custom regex report a
+    /// [`PatternMismatch`](InvalidCurieReason::PatternMismatch).
+    fn explain(&self, curie: &str) -> Option<InvalidCurieReason> {
+        if self.validate(curie) {
+            return None;
+        }
+        match self.metadata {
+            Some(metadata) => Some(diagnose_ontology(metadata, curie)),
+            None => Some(diagnose_structure(curie).unwrap_or_else(|| {
+                InvalidCurieReason::PatternMismatch {
+                    pattern: self.regex.as_str().to_string(),
+                }
+            })),
+        }
+    }
 }
 
 impl From<Regex> for CurieRegexValidator {
     fn from(value: Regex) -> Self {
-        Self { regex: value }
+        Self {
+            regex: value,
+            metadata: None,
+        }
     }
 }
 
@@ -46,7 +104,7 @@ impl TryFrom<&str> for CurieRegexValidator {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let regex = Regex::new(value).map_err(InvalidRegexError)?;
-        Ok(Self { regex })
+        Ok(Self::from(regex))
     }
 }
 
@@ -55,6 +113,6 @@ impl TryFrom<String> for CurieRegexValidator {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let regex = Regex::new(&value).map_err(InvalidRegexError)?;
-        Ok(Self { regex })
+        Ok(Self::from(regex))
     }
 }
@@ -1,4 +1,9 @@
-use securiety::{CurieParser, CurieParsing, CurieRegexValidator, CurieValidation};
+use securiety::{
+    AllOf, AllowListParser, AnyOf, Curie, CurieClassifier, CurieId, CurieInterner, CurieParser,
+    CurieParsing, CurieParsingError, CurieRegexValidator, CurieValidation, InvalidCurieReason,
+    NaturalOrd, Not, Ontology, OntologyMetadata, PackedCurieArena, PrefixIs, PrefixMap,
+    PrefixNormalization, RegistryParser, Rewrite, UnknownPrefixPolicy, supported_prefixes,
+};
 
 #[test]
 fn test_integration_parsing() {
@@ -46,6 +51,17 @@ fn test_integration_regex_validation() {
     assert!(!validator.validate("MONDO:0000054"));
 }
 
+#[test]
+fn test_integration_regex_validation_alternations_are_anchored() {
+    let validator = CurieRegexValidator::ncbitaxon();
+    assert!(validator.validate("NCBITaxon:9606"));
+    assert!(!validator.validate("HP:abc"));
+
+    let validator = CurieRegexValidator::icd9cm();
+    assert!(validator.validate("ICD9CM:250.01"));
+    assert!(!validator.validate("HP:0000054"));
+}
+
 #[test]
 fn test_integration_regex_validation_general() {
     let validator = CurieRegexValidator::general();
@@ -96,3 +112,404 @@ fn test_integration_regex_validation_general() {
     // Illegal Characters (Control chars, pipes, etc - unless explicitly allowed)
     assert!(!validator.validate("HP:00\n054"));
 }
+
+#[test]
+fn test_integration_expansion() {
+    let prefix_map = PrefixMap::bioregistry();
+    let curie = CurieParser::from_prefix("ncbitaxon")
+        .unwrap()
+        .parse("NCBITaxon:9606")
+        .unwrap();
+
+    assert_eq!(
+        curie.expand(&prefix_map).as_deref(),
+        Some("http://purl.obolibrary.org/obo/NCBITaxon_9606")
+    );
+
+    let curie = CurieParser::general().parse("custom:42").unwrap();
+    assert_eq!(curie.expand(&prefix_map), None);
+}
+
+#[test]
+fn test_integration_expansion_roundtrip() {
+    let prefix_map = PrefixMap::bioregistry();
+    let parser = CurieParser::from_prefix("ordo").unwrap();
+    let curie = parser.parse("ORDO:558").unwrap();
+
+    let iri = curie.expand(&prefix_map).unwrap();
+    assert_eq!(iri, "http://www.orpha.net/ORDO/Orphanet_558");
+    assert_eq!(prefix_map.compress(&iri), Some(curie));
+}
+
+#[test]
+fn test_integration_metadata() {
+    let parser = CurieParser::from_prefix("omim").unwrap();
+    let metadata = parser.metadata().unwrap();
+
+    assert_eq!(metadata.prefix(), "OMIM");
+    assert_eq!(metadata.name(), "Online Mendelian Inheritance in Man");
+    assert_eq!(metadata.homepage(), Some("https://omim.org/"));
+    assert_eq!(metadata.uri_format(), Some("https://omim.org/entry/$1"));
+    assert!(
+        parser
+            .parse(&format!("OMIM:{}", metadata.example().unwrap()))
+            .is_ok()
+    );
+
+    assert!(CurieParser::general().metadata().is_none());
+}
+
+#[test]
+fn test_integration_supported_prefixes() {
+    let prefixes = supported_prefixes();
+
+    assert!(prefixes.contains(&"HP"));
+    assert!(prefixes.contains(&"NCBITaxon"));
+    assert!(!prefixes.contains(&"general"));
+
+    for prefix in prefixes {
+        let validator = CurieRegexValidator::from_prefix(prefix).unwrap();
+        assert_eq!(
+            validator.metadata().map(OntologyMetadata::prefix),
+            Some(*prefix)
+        );
+    }
+}
+
+#[test]
+fn test_integration_ontology_enum() {
+    let ontology: Ontology = "ncbitaxon".parse().unwrap();
+
+    assert_eq!(ontology, Ontology::Ncbitaxon);
+    assert_eq!(ontology.to_string(), "NCBITaxon");
+    assert!(ontology.parser().parse("NCBITaxon:9606").is_ok());
+    assert!(ontology.validator().validate("NCBITaxon:9606"));
+    assert_eq!(ontology.metadata().name(), "NCBI Taxonomy");
+
+    assert!("NotAnOntology".parse::<Ontology>().is_err());
+    assert_eq!(Ontology::ALL.len(), supported_prefixes().len());
+}
+
+#[test]
+fn test_integration_registry_parser() {
+    let parser = RegistryParser::default();
+
+    let curies: Vec<_> = ["HP:0000054", "MONDO:0000054", "OMIM:603903"]
+        .into_iter()
+        .map(|curie| parser.parse(curie).unwrap())
+        .collect();
+    assert_eq!(curies[1].prefix(), "MONDO");
+
+    assert!(parser.parse("MONDO:54").is_err());
+    assert!(parser.parse("custom:54").is_err());
+    assert!(
+        RegistryParser::new(UnknownPrefixPolicy::General)
+            .parse("custom:54")
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_integration_allow_list_parser() {
+    let parser = CurieParser::any_of(&["MONDO", "OMIM", "ORDO"]).unwrap();
+
+    assert!(parser.parse("MONDO:0000054").is_ok());
+    assert!(parser.parse("ORDO:558").is_ok());
+    match parser.parse("HP:0000054") {
+        Err(CurieParsingError::PrefixNotAllowed { allowed, .. }) => {
+            assert_eq!(allowed, ["MONDO", "OMIM", "ORDO"])
+        }
+        result => panic!("Expected PrefixNotAllowed error, got {:?}", result),
+    }
+
+    let from_ontologies: AllowListParser = [Ontology::Mondo, Ontology::Omim, Ontology::Ordo]
+        .into_iter()
+        .collect();
+    assert_eq!(parser, from_ontologies);
+}
+
+#[test]
+fn test_integration_combinators() {
+    let validator = AllOf((
+        AnyOf([CurieRegexValidator::mondo(), CurieRegexValidator::omim()]),
+        Not(PrefixIs("OMIM")),
+    ));
+
+    assert!(validator.validate("MONDO:0000054"));
+    assert!(!validator.validate("OMIM:603903"));
+    assert!(!validator.validate("HP:0000054"));
+
+    let parser = CurieParser::new(&validator);
+    assert!(parser.parse("MONDO:0000054").is_ok());
+}
+
+#[test]
+fn test_integration_invalid_curie_reasons() {
+    let parser = CurieParser::hp();
+
+    let reason = |curie: &str| match parser.parse(curie) {
+        Err(CurieParsingError::InvalidCurie { reason, .. }) => reason,
+        result => panic!("Expected InvalidCurie error, got {:?}", result),
+    };
+
+    assert_eq!(reason("HP0000054"), InvalidCurieReason::MissingSeparator);
+    assert_eq!(reason(":0000054"), InvalidCurieReason::EmptyPrefix);
+    assert_eq!(reason("HP:"), InvalidCurieReason::EmptyReference);
+    assert_eq!(
+        reason("HP: 0000054"),
+        InvalidCurieReason::IllegalCharacter {
+            offset: 3,
+            character: ' '
+        }
+    );
+    assert_eq!(
+        reason("hp:0000054"),
+        InvalidCurieReason::PrefixMismatch {
+            expected: "HP",
+            found: "hp".to_string()
+        }
+    );
+    assert_eq!(
+        reason("HP:123"),
+        InvalidCurieReason::ReferenceMismatch {
+            pattern: r"^HP:\d{7}$",
+            example: Some("0000118")
+        }
+    );
+
+    let error = parser.parse("HP:123").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        r"Invalid CURIE: HP:123 (reference does not match `^HP:\d{7}$`, e.g. `0000118`)"
+    );
+}
+
+#[test]
+fn test_integration_prefix_suggestions() {
+    let error = CurieParser::try_from_prefix("MIM").unwrap_err();
+    assert_eq!(error.prefix(), "MIM");
+    assert_eq!(error.suggestions()[0], "OMIM");
+
+    let error = "NCBI_Taxon".parse::<Ontology>().unwrap_err();
+    assert_eq!(error.suggestions(), ["NCBITaxon"]);
+
+    assert!(CurieRegexValidator::try_from_prefix("general").is_ok());
+    assert_eq!(
+        OntologyMetadata::from_prefix("OMIM").unwrap().synonyms(),
+        ["MIM"]
+    );
+}
+
+#[test]
+fn test_integration_prefix_normalization() {
+    let parser = CurieParser::from_prefix("hp")
+        .unwrap()
+        .with_prefix_normalization(PrefixNormalization::Case);
+
+    let curie = parser.parse("hp:0000054").unwrap();
+    assert_eq!(curie.prefix(), "HP");
+    assert_eq!(curie.to_string(), "HP:0000054");
+    assert!(parser.parse("HPO:0000054").is_err());
+
+    let parser = parser.with_prefix_normalization(PrefixNormalization::Synonyms);
+    assert_eq!(parser.parse("HPO:0000054").unwrap().prefix(), "HP");
+}
+
+#[test]
+fn test_integration_parse_lenient() {
+    let parser = RegistryParser::default();
+
+    for input in [
+        "HP_0000054",
+        "obo:HP_0000054",
+        "http://purl.obolibrary.org/obo/HP_0000054",
+        "<HP:0000054>",
+    ] {
+        let parsed = parser.parse_lenient(input).unwrap();
+        assert_eq!(parsed.curie().to_string(), "HP:0000054");
+        assert!(!parsed.rewrites().is_empty());
+    }
+
+    assert!(
+        CurieParser::ordo()
+            .parse_lenient("http://www.orpha.net/ORDO/Orphanet_558")
+            .is_err()
+    );
+
+    let parsed = CurieParser::hp().parse_lenient(" HP:0000054 ").unwrap();
+    assert_eq!(parsed.rewrites(), [Rewrite::Trimmed]);
+}
+
+#[test]
+fn test_integration_banana() {
+    let parser = CurieParser::go();
+
+    match parser.parse("GO:GO:0008150") {
+        Err(CurieParsingError::InvalidCurie { reason, .. }) => {
+            assert_eq!(reason, InvalidCurieReason::Banana { banana: "GO:" })
+        }
+        result => panic!("Expected InvalidCurie error, got {:?}", result),
+    }
+
+    let parsed = parser.parse_lenient("GO:GO:0008150").unwrap();
+    assert_eq!(parsed.curie().to_string(), "GO:0008150");
+    assert_eq!(parsed.rewrites(), [Rewrite::Banana]);
+}
+
+#[test]
+fn test_integration_zero_padding() {
+    let parser = RegistryParser::default();
+
+    let parsed = parser.parse_zero_padded("MONDO:54").unwrap();
+    assert_eq!(parsed.curie().to_string(), "MONDO:0000054");
+    assert_eq!(parsed.rewrites(), [Rewrite::ZeroPadded]);
+
+    assert!(parser.parse_zero_padded("MONDO:00000054").is_err());
+    assert!(parser.parse_zero_padded("MONDO:x54").is_err());
+    assert!(!parser.parse_zero_padded("OMIM:54").unwrap().is_modified());
+
+    let parsed = parser.parse_zero_padded("FOODON:123").unwrap();
+    assert_eq!(parsed.curie().to_string(), "FOODON:00000123");
+}
+
+#[test]
+fn test_integration_parse_ref() {
+    let rows = "MONDO:0006007\nOMIM:603903\nHP:0000118";
+    let parser = CurieParser::any_of(&["MONDO", "OMIM"]).unwrap();
+
+    let parsed: Vec<_> = rows.lines().map(|row| parser.parse_ref(row)).collect();
+    assert_eq!(parsed[0].as_ref().unwrap().prefix(), "MONDO");
+    assert_eq!(
+        parsed[1].as_ref().unwrap().to_owned().to_string(),
+        "OMIM:603903"
+    );
+    assert!(parsed[2].is_err());
+}
+
+#[test]
+fn test_integration_parse_ref_of_custom_parser() {
+    struct UppercaseParser;
+
+    impl CurieParsing for UppercaseParser {
+        fn parse(&self, curie: &str) -> Result<Curie, CurieParsingError> {
+            CurieParser::general().parse(&curie.to_uppercase())
+        }
+    }
+
+    let curie = UppercaseParser.parse_ref("MONDO:0006007").unwrap();
+    assert_eq!(curie.to_string(), "MONDO:0006007");
+
+    match UppercaseParser.parse_ref("custom:x") {
+        Err(CurieParsingError::UnparsableCurie(curie)) => assert_eq!(curie, "custom:x"),
+        result => panic!("Expected UnparsableCurie error, got {:?}", result),
+    }
+}
+
+#[test]
+fn test_integration_packed_curie() {
+    let parser = RegistryParser::new(UnknownPrefixPolicy::General);
+    let raw = [
+        "MONDO:0006007",
+        "HP:0000118",
+        "MESH:D009369",
+        "custom:42",
+        "HP:0000054",
+    ];
+
+    let mut arena = PackedCurieArena::new();
+    let mut curies: Vec<_> = raw.iter().map(|raw| parser.parse(raw).unwrap()).collect();
+    let mut packed: Vec<_> = curies.iter().map(|curie| arena.pack(curie)).collect();
+    curies.sort();
+    packed.sort_by(|a, b| arena.cmp(a, b));
+
+    let unpacked: Vec<Curie> = packed.iter().map(|&p| arena.to_curie(p)).collect();
+    assert_eq!(unpacked, curies);
+    assert_eq!(packed[1].ontology(), Some(Ontology::Hp));
+    assert_eq!(arena.prefix(packed[4]), "custom");
+    assert_eq!(arena.display(packed[2]).to_string(), "MESH:D009369");
+}
+
+#[test]
+fn test_integration_curie_interner() {
+    let parser = RegistryParser::default();
+    let interner = CurieInterner::new();
+    let edges = [("HP:0000118", "HP:0000001"), ("HP:0000054", "HP:0000118")];
+
+    let graph: Vec<(CurieId, CurieId)> = edges
+        .iter()
+        .map(|(child, parent)| {
+            (
+                interner.intern(&parser.parse(child).unwrap()),
+                interner.intern(&parser.parse(parent).unwrap()),
+            )
+        })
+        .collect();
+
+    assert_eq!(interner.len(), 3);
+    assert_eq!(graph[0].0, graph[1].1);
+    assert_eq!(
+        interner.resolve(graph[1].0).unwrap().to_string(),
+        "HP:0000054"
+    );
+}
+
+#[test]
+fn test_integration_natural_ordering() {
+    let parser = RegistryParser::default();
+    let mut curies: Vec<_> = ["CHEBI:100", "HP:0000118", "CHEBI:15377", "CHEBI:27"]
+        .iter()
+        .map(|raw| NaturalOrd(parser.parse(raw).unwrap()))
+        .collect();
+
+    curies.sort();
+
+    let sorted: Vec<_> = curies.iter().map(|curie| curie.0.to_string()).collect();
+    assert_eq!(
+        sorted,
+        ["CHEBI:27", "CHEBI:100", "CHEBI:15377", "HP:0000118"]
+    );
+}
+
+#[test]
+fn test_integration_numeric_references() {
+    let parser = RegistryParser::default();
+
+    for raw in [
+        "HP:0000118",
+        "CHEBI:15377",
+        "NCBITaxon:9606",
+        "MONDO:0006007",
+    ] {
+        let curie = parser.parse(raw).unwrap();
+        let ontology = Ontology::from_prefix(curie.prefix()).unwrap();
+        let number = curie.reference_as_u64().unwrap();
+
+        assert_eq!(ontology.curie_from_number(number).unwrap(), curie);
+    }
+}
+
+#[test]
+fn test_integration_curie_classifier() {
+    let classifier = CurieClassifier::new();
+
+    assert_eq!(classifier.classify("MONDO:0006007"), [Ontology::Mondo]);
+    assert!(classifier.classify("custom:42").is_empty());
+
+    for ontology in classifier.classify_reference("0008150") {
+        let curie = format!("{}:0008150", ontology.prefix());
+        assert!(ontology.parser().parse(&curie).is_ok());
+    }
+}
+
+#[cfg(feature = "macros")]
+#[test]
+fn test_integration_curie_macro() {
+    let curie = securiety::curie!("FBbt:00000001");
+
+    assert_eq!(curie.prefix(), "FBbt");
+    assert_eq!(curie.reference(), "00000001");
+    assert_eq!(
+        curie,
+        RegistryParser::default().parse("FBbt:00000001").unwrap()
+    );
+}
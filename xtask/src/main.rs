@@ -0,0 +1,684 @@
+//! Development tasks for securiety.
+//!
+//! - `cargo xtask codegen` regenerates `src/validators/curie_patterns.rs` and the pattern
+//!   table of the `curie!` macro in `macros/src/patterns.rs` from the Bioregistry snapshot
+//!   in `data/bioregistry.json`. With `--check`, it fails if a checked-in file is out of
+//!   date instead of writing it.
+//! - `cargo xtask prune <registry.json> <release>` replaces the snapshot with the resources
+//!   selected from a full Bioregistry export (`exports/registry/registry.json` in the
+//!   Bioregistry repository), keeping only the fields the code generation reads. The release
+//!   or commit of the export is recorded in `data/bioregistry.release`, and the generated
+//!   code refers to it.
+//!
+//! Neither task touches the network, so updating the patterns is a reviewable change to
+//! the snapshot and the generated file.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::{env, fs};
+
+const SNAPSHOT_PATH: &str = "data/bioregistry.json";
+const RELEASE_PATH: &str = "data/bioregistry.release";
+const PATTERNS_PATH: &str = "src/validators/curie_patterns.rs";
+const MACRO_PATTERNS_PATH: &str = "macros/src/patterns.rs";
+
+/// Resources that are generated even though they have no OBO or BioPortal mapping.
+/// Their prefix is the preferred prefix from the Bioregistry.
+const EXTRA_RESOURCES: [&str; 1] = ["drugcentral"];
+
+/// Pattern constants that were renamed, with their current name. A deprecated alias is
+/// generated for each, so that code using the old name keeps compiling.
+const RENAMED_CONSTS: [(&str, &str); 1] = [("DRUGCENTRAL", "DRUGCENTRAL_PATTERN")];
+
+const STRICT_KEYWORDS: [&str; 39] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "gen",
+];
+
+type Registry = BTreeMap<String, Resource>;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Resource {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    example: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    license: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    preferred_prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    synonyms: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    banana: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    banana_peel: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    uri_format: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mappings: Option<Mappings>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Mappings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bioportal: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ontobee: Option<String>,
+}
+
+/// A resource that a validator is generated for.
+struct Ontology<'a> {
+    key: &'a str,
+    prefix: String,
+    resource: &'a Resource,
+    pattern: &'a str,
+}
+
+impl Ontology<'_> {
+    fn fn_name(&self) -> String {
+        self.prefix.to_lowercase()
+    }
+
+    fn const_name(&self) -> String {
+        format!("{}_PATTERN", self.prefix.to_uppercase())
+    }
+
+    /// The Bioregistry pattern, anchored to the prefix.
+    ///
+    /// Bioregistry patterns only cover the reference. Alternations are grouped, so that the
+    /// anchors and the prefix apply to every branch.
+    fn prefixed_pattern(&self) -> String {
+        let body = self.pattern.strip_prefix('^').unwrap_or(self.pattern);
+        let body = body.strip_suffix('$').unwrap_or(body);
+        if has_top_level_alternation(body) {
+            format!("^{}:(?:{body})$", self.prefix)
+        } else {
+            format!("^{}:{body}$", self.prefix)
+        }
+    }
+
+    /// The text that some references repeat before the local ID, e.g. `GO:` in
+    /// `GO:GO:0008150`. The Bioregistry's default peel is a colon.
+    fn banana(&self) -> Option<String> {
+        let banana = self.resource.banana.as_ref()?;
+        let peel = self.resource.banana_peel.as_deref().unwrap_or(":");
+        Some(format!("{banana}{peel}"))
+    }
+
+    fn uri_format(&self) -> Option<String> {
+        if let Some(uri_format) = &self.resource.uri_format {
+            return Some(uri_format.clone());
+        }
+        self.resource
+            .mappings
+            .as_ref()
+            .and_then(|mappings| mappings.ontobee.as_ref())
+            .map(|_| format!("http://purl.obolibrary.org/obo/{}_$1", self.prefix))
+    }
+}
+
+/// The Bioregistry synonyms of each ontology's prefix.
+///
+/// Case variants of the prefix itself are dropped, as are synonyms that are the prefix of
+/// another ontology or a synonym of an earlier one, so that every synonym refers to exactly
+/// one ontology.
+fn synonyms<'a>(ontologies: &'a [Ontology<'a>]) -> Vec<Vec<&'a str>> {
+    let mut seen: HashSet<String> = ontologies
+        .iter()
+        .map(|ontology| ontology.prefix.to_lowercase())
+        .collect();
+
+    ontologies
+        .iter()
+        .map(|ontology| {
+            ontology
+                .resource
+                .synonyms
+                .iter()
+                .flatten()
+                .map(String::as_str)
+                .filter(|synonym| seen.insert(synonym.to_lowercase()))
+                .collect()
+        })
+        .collect()
+}
+
+fn has_top_level_alternation(pattern: &str) -> bool {
+    let mut depth = 0usize;
+    let mut in_class = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '(' if !in_class => depth += 1,
+            ')' if !in_class => depth = depth.saturating_sub(1),
+            '|' if !in_class && depth == 0 => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+fn select(registry: &Registry) -> Vec<Ontology<'_>> {
+    let mut seen = HashSet::new();
+    let mut ontologies = Vec::new();
+
+    for (key, resource) in registry {
+        let Some(pattern) = resource.pattern.as_deref() else {
+            continue;
+        };
+
+        let prefix = if EXTRA_RESOURCES.contains(&key.as_str()) {
+            resource
+                .preferred_prefix
+                .clone()
+                .unwrap_or_else(|| key.clone())
+        } else {
+            let Some(mappings) = &resource.mappings else {
+                continue;
+            };
+            match mappings.ontobee.as_ref().or(mappings.bioportal.as_ref()) {
+                Some(prefix) => prefix.clone(),
+                None => continue,
+            }
+        };
+
+        let prefix_lower = prefix.to_lowercase();
+        if prefix.contains('-')
+            || STRICT_KEYWORDS.contains(&prefix_lower.as_str())
+            || prefix_lower == "general"
+            || !seen.insert(prefix_lower)
+        {
+            continue;
+        }
+
+        ontologies.push(Ontology {
+            key,
+            prefix,
+            resource,
+            pattern,
+        });
+    }
+
+    ontologies
+}
+
+/// The line of the generated files' docs that names their source.
+fn generated_from(release: Option<&str>) -> String {
+    let release = match release {
+        Some(release) => format!("Bioregistry release `{release}`"),
+        None => "no recorded Bioregistry release".to_string(),
+    };
+    format!(
+        "//! @generated by `cargo xtask codegen` from `{SNAPSHOT_PATH}` ({release}). Do not edit by hand.\n"
+    )
+}
+
+fn generate(registry: &Registry, release: Option<&str>) -> String {
+    let mut code = String::with_capacity(100 * 1024);
+    code.push_str("//! CURIE patterns of the ontologies in the Bioregistry.\n//!\n");
+    code.push_str(&generated_from(release));
+    code.push_str(
+        "\n\
+         use crate::curie_parser::CurieParser;\n\
+         use crate::metadata::OntologyMetadata;\n\
+         use crate::validators::regex_validator::CurieRegexValidator;\n\
+         use paste::paste;\n\
+         use std::sync::OnceLock;\n\
+         \n\
+         /// The Bioregistry release or commit the patterns and metadata were generated from, or\n\
+         /// `None` if the snapshot does not record one.\n",
+    );
+    let _ = writeln!(
+        code,
+        "pub const BIOREGISTRY_RELEASE: Option<&str> = {};\n",
+        option_literal(&release.map(str::to_string))
+    );
+    code.push_str("define_curie_validators! {\n");
+
+    let ontologies = select(registry);
+    let synonyms = synonyms(&ontologies);
+    for (ontology, synonyms) in ontologies.iter().zip(&synonyms) {
+        let resource = ontology.resource;
+        let name = resource.name.as_deref().unwrap_or(&ontology.prefix);
+        let _ = writeln!(
+            code,
+            "    {}, {} => {:?} {{",
+            ontology.fn_name(),
+            ontology.const_name(),
+            ontology.prefixed_pattern()
+        );
+        let _ = writeln!(code, "        prefix: {:?},", ontology.prefix);
+        let _ = writeln!(code, "        name: {name:?},");
+        let _ = writeln!(code, "        synonyms: &{synonyms:?},");
+        let _ = writeln!(
+            code,
+            "        description: {},",
+            option_literal(&resource.description)
+        );
+        let _ = writeln!(
+            code,
+            "        homepage: {},",
+            option_literal(&resource.homepage)
+        );
+        let _ = writeln!(
+            code,
+            "        example: {},",
+            option_literal(&resource.example)
+        );
+        let _ = writeln!(
+            code,
+            "        banana: {},",
+            option_literal(&ontology.banana())
+        );
+        let _ = writeln!(
+            code,
+            "        uri_format: {},",
+            option_literal(&ontology.uri_format())
+        );
+        let _ = writeln!(
+            code,
+            "        license: {},",
+            option_literal(&resource.license)
+        );
+        code.push_str("    },\n");
+    }
+
+    code.push_str("}\n");
+
+    let prefixes = ontologies
+        .iter()
+        .enumerate()
+        .map(|(index, ontology)| (ontology.prefix.as_str(), index));
+    write_lookup_table(
+        &mut code,
+        "The preferred prefixes of all ontologies",
+        "PREFIXES_LOWERCASE",
+        prefixes,
+    );
+    let synonyms = synonyms
+        .iter()
+        .enumerate()
+        .flat_map(|(index, synonyms)| synonyms.iter().map(move |&synonym| (synonym, index)));
+    write_lookup_table(
+        &mut code,
+        "The Bioregistry synonyms of all ontologies",
+        "SYNONYMS_LOWERCASE",
+        synonyms,
+    );
+
+    let const_names: HashSet<_> = ontologies.iter().map(Ontology::const_name).collect();
+    for (old, new) in RENAMED_CONSTS {
+        if const_names.contains(new) {
+            let _ = write!(
+                code,
+                "\n#[deprecated(note = \"renamed to `{new}`\")]\npub const {old}: &str = {new};\n"
+            );
+        }
+    }
+    code
+}
+
+/// Writes a table of ASCII-lowercased prefixes and the indices of their ontologies, sorted
+/// for a binary search.
+fn write_lookup_table<'a>(
+    code: &mut String,
+    doc: &str,
+    name: &str,
+    prefixes: impl Iterator<Item = (&'a str, usize)>,
+) {
+    let mut entries: Vec<_> = prefixes
+        .map(|(prefix, index)| {
+            let index = u16::try_from(index).expect("ontology indices fit a u16");
+            (prefix.to_ascii_lowercase(), index)
+        })
+        .collect();
+    entries.sort();
+
+    let _ = writeln!(
+        code,
+        "\n/// {doc} in ASCII lowercase, sorted, with the index of their [`Ontology`] in\n\
+         /// [`Ontology::ALL`].\n\
+         #[rustfmt::skip]\n\
+         pub(crate) static {name}: &[(&str, u16)] = &["
+    );
+    for (prefix, index) in entries {
+        let _ = writeln!(code, "    ({prefix:?}, {index}),");
+    }
+    code.push_str("];\n");
+}
+
+/// The pattern table that the `curie!` macro validates against at compile time.
+///
+/// The proc-macro crate cannot depend on securiety, so it gets its own copy of the prefixes,
+/// patterns, examples and bananas, in the same order as the validators.
+fn generate_macro_patterns(registry: &Registry, release: Option<&str>) -> String {
+    let mut code = String::with_capacity(30 * 1024);
+    code.push_str(
+        "//! CURIE patterns of the ontologies in the Bioregistry, checked by `curie!`.\n//!\n",
+    );
+    code.push_str(&generated_from(release));
+    code.push_str(
+        "\n\
+         /// The prefix, pattern, example reference and banana of every supported ontology.\n\
+         #[rustfmt::skip]\n\
+         pub(crate) const PATTERNS: &[(&str, &str, Option<&str>, Option<&str>)] = &[\n",
+    );
+
+    for ontology in select(registry) {
+        let _ = writeln!(
+            code,
+            "    ({:?}, {:?}, {}, {}),",
+            ontology.prefix,
+            ontology.prefixed_pattern(),
+            option_literal(&ontology.resource.example),
+            option_literal(&ontology.banana())
+        );
+    }
+
+    code.push_str("];\n");
+    code
+}
+
+fn option_literal(value: &Option<String>) -> String {
+    match value {
+        Some(value) => format!("Some({value:?})"),
+        None => "None".to_string(),
+    }
+}
+
+fn prune(registry: &Registry) -> Registry {
+    select(registry)
+        .into_iter()
+        .map(|ontology| (ontology.key.to_string(), ontology.resource.clone()))
+        .collect()
+}
+
+fn read_registry(path: &Path) -> Result<Registry, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|err| format!("Unable to read {}: {err}", path.display()))?;
+    serde_json::from_str(&text).map_err(|err| {
+        format!(
+            "Unable to parse {} at line {}: {err}",
+            path.display(),
+            err.line()
+        )
+    })
+}
+
+/// Reads the release the snapshot was pruned from, or `None` if none is recorded.
+fn read_release(path: &Path) -> Result<Option<String>, String> {
+    match fs::read_to_string(path) {
+        Ok(release) => Ok(Some(release.trim().to_string())),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(format!("Unable to read {}: {err}", path.display())),
+    }
+}
+
+fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives in the workspace root")
+        .to_path_buf()
+}
+
+fn codegen(check: bool) -> Result<(), String> {
+    let root = root();
+    let registry = read_registry(&root.join(SNAPSHOT_PATH))?;
+    let release = read_release(&root.join(RELEASE_PATH))?;
+    let outputs = [
+        (PATTERNS_PATH, generate(&registry, release.as_deref())),
+        (
+            MACRO_PATTERNS_PATH,
+            generate_macro_patterns(&registry, release.as_deref()),
+        ),
+    ];
+
+    for (relative_path, code) in outputs {
+        let path = root.join(relative_path);
+        if check {
+            let current = fs::read_to_string(&path)
+                .map_err(|err| format!("Unable to read {}: {err}", path.display()))?;
+            if current != code {
+                return Err(format!(
+                    "{relative_path} is out of date, run `cargo xtask codegen`"
+                ));
+            }
+        } else {
+            fs::write(&path, code)
+                .map_err(|err| format!("Unable to write {}: {err}", path.display()))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn prune_snapshot(export: &Path, release: &str) -> Result<(), String> {
+    let release = release.trim();
+    if release.is_empty() {
+        return Err("The Bioregistry release must not be empty".into());
+    }
+    let registry = read_registry(export)?;
+    let mut json = serde_json::to_string_pretty(&prune(&registry))
+        .map_err(|err| format!("Unable to serialize the snapshot: {err}"))?;
+    json.push('\n');
+
+    let root = root();
+    for (relative_path, contents) in [
+        (SNAPSHOT_PATH, json),
+        (RELEASE_PATH, format!("{release}\n")),
+    ] {
+        let path = root.join(relative_path);
+        fs::write(&path, contents)
+            .map_err(|err| format!("Unable to write {}: {err}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .as_slice()
+    {
+        ["codegen"] => codegen(false),
+        ["codegen", "--check"] => codegen(true),
+        ["prune", export, release] => prune_snapshot(Path::new(export), release),
+        _ => Err(
+            "Usage: cargo xtask codegen [--check] | cargo xtask prune <registry.json> <release>"
+                .into(),
+        ),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(pattern: &str, ontobee: Option<&str>, bioportal: Option<&str>) -> Resource {
+        Resource {
+            name: None,
+            description: None,
+            homepage: None,
+            example: None,
+            license: None,
+            pattern: Some(pattern.to_string()),
+            preferred_prefix: None,
+            synonyms: None,
+            banana: None,
+            banana_peel: None,
+            uri_format: None,
+            mappings: Some(Mappings {
+                bioportal: bioportal.map(str::to_string),
+                ontobee: ontobee.map(str::to_string),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_top_level_alternation() {
+        assert!(has_top_level_alternation(r"(\d+)|([a-zA-Z_]+)"));
+        assert!(!has_top_level_alternation(r"(HOM)?\d{7}"));
+        assert!(!has_top_level_alternation(r"(UCR|UCY)\d{5}"));
+        assert!(!has_top_level_alternation(r"[|]\d+"));
+        assert!(!has_top_level_alternation(r"\|\d+"));
+    }
+
+    #[test]
+    fn test_select_prefers_ontobee_prefix_and_skips_invalid() {
+        let registry = Registry::from([
+            (
+                "fbbt".to_string(),
+                resource(r"^\d{8}$", Some("FBbt"), Some("FB-BT")),
+            ),
+            (
+                "dashed".to_string(),
+                resource(r"^\d+$", None, Some("DASH-ED")),
+            ),
+            (
+                "keyword".to_string(),
+                resource(r"^\d+$", Some("TYPE"), None),
+            ),
+            ("unmapped".to_string(), resource(r"^\d+$", None, None)),
+        ]);
+
+        let prefixes: Vec<_> = select(&registry)
+            .into_iter()
+            .map(|ontology| ontology.prefix)
+            .collect();
+        assert_eq!(prefixes, ["FBbt"]);
+    }
+
+    #[test]
+    fn test_synonyms_refer_to_one_ontology() {
+        let mut hp = resource(r"^\d{7}$", Some("HP"), None);
+        hp.synonyms = Some(vec![
+            "HPO".into(),
+            "hp".into(),
+            "MP".into(),
+            "shared".into(),
+        ]);
+        let mut mp = resource(r"^\d{7}$", Some("MP"), None);
+        mp.synonyms = Some(vec!["SHARED".into(), "MPO".into()]);
+        let registry = Registry::from([("hp".to_string(), hp), ("mp".to_string(), mp)]);
+
+        let ontologies = select(&registry);
+        assert_eq!(synonyms(&ontologies), [vec!["HPO", "shared"], vec!["MPO"]]);
+    }
+
+    #[test]
+    fn test_banana_defaults_to_colon_peel() {
+        let mut go = resource(r"^\d{7}$", Some("GO"), None);
+        go.banana = Some("GO".to_string());
+        let mut apollo = resource(r"^\d{8}$", Some("APOLLO_SV"), None);
+        apollo.banana = Some("APOLLO_SV".to_string());
+        apollo.banana_peel = Some("_".to_string());
+        let registry = Registry::from([("go".to_string(), go), ("apollosv".to_string(), apollo)]);
+
+        let bananas: Vec<_> = select(&registry).iter().map(Ontology::banana).collect();
+        assert_eq!(
+            bananas,
+            [Some("APOLLO_SV_".to_string()), Some("GO:".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_generated_entry() {
+        let registry = Registry::from([(
+            "ncbitaxon".to_string(),
+            resource(r"^(\d+)|([a-zA-Z_]+)$", Some("NCBITaxon"), None),
+        )]);
+
+        let code = generate(&registry, None);
+        assert!(code.contains("pub const BIOREGISTRY_RELEASE: Option<&str> = None;"));
+        assert!(code.contains(concat!(
+            "    ncbitaxon, NCBITAXON_PATTERN => \"^NCBITaxon:(?:(\\\\d+)|([a-zA-Z_]+))$\" {\n",
+            "        prefix: \"NCBITaxon\",\n",
+            "        name: \"NCBITaxon\",\n",
+            "        synonyms: &[],\n",
+            "        description: None,\n",
+            "        homepage: None,\n",
+            "        example: None,\n",
+            "        banana: None,\n",
+            "        uri_format: Some(\"http://purl.obolibrary.org/obo/NCBITaxon_$1\"),\n",
+            "        license: None,\n",
+            "    },\n",
+        )));
+    }
+
+    #[test]
+    fn test_generates_deprecated_alias_for_renamed_const() {
+        let mut drugcentral = resource(r"^\d+$", None, None);
+        drugcentral.preferred_prefix = Some("DrugCentral".to_string());
+        let registry = Registry::from([("drugcentral".to_string(), drugcentral)]);
+
+        assert!(generate(&registry, None).ends_with(concat!(
+            "#[deprecated(note = \"renamed to `DRUGCENTRAL_PATTERN`\")]\n",
+            "pub const DRUGCENTRAL: &str = DRUGCENTRAL_PATTERN;\n",
+        )));
+        assert!(!generate(&Registry::new(), None).contains("DRUGCENTRAL"));
+    }
+
+    #[test]
+    fn test_lookup_table_is_lowercase_and_sorted() {
+        let mut code = String::new();
+        write_lookup_table(
+            &mut code,
+            "Prefixes",
+            "TABLE",
+            [("NCBITaxon", 1), ("FBbt", 0), ("GO", 2)].into_iter(),
+        );
+
+        assert!(code.ends_with(concat!(
+            "pub(crate) static TABLE: &[(&str, u16)] = &[\n",
+            "    (\"fbbt\", 0),\n",
+            "    (\"go\", 2),\n",
+            "    (\"ncbitaxon\", 1),\n",
+            "];\n",
+        )));
+    }
+
+    #[test]
+    fn test_generated_code_names_release() {
+        let registry = Registry::new();
+
+        for code in [
+            generate(&registry, Some("0.11.30")),
+            generate_macro_patterns(&registry, Some("0.11.30")),
+        ] {
+            assert!(code.contains(
+                "from `data/bioregistry.json` (Bioregistry release `0.11.30`). Do not edit"
+            ));
+        }
+        assert!(
+            generate(&registry, Some("0.11.30"))
+                .contains("pub const BIOREGISTRY_RELEASE: Option<&str> = Some(\"0.11.30\");")
+        );
+    }
+}